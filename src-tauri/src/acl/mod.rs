@@ -0,0 +1,191 @@
+//! In-process capability gate for destructive Tauri commands
+//!
+//! `run()` registers every command into one `invoke_handler`, which puts
+//! them all behind the same IPC boundary Tauri's own declarative ACL
+//! (`tauri.conf.json` capabilities) governs - useful against a malicious
+//! *external* page, but it doesn't stop the app's own webview content,
+//! if it's ever compromised or fed injected script, from invoking
+//! `uninstall_tool` or `kill_process_cmd` just as freely as a read-only
+//! scan. This module adds a second, in-memory gate in front of the
+//! commands where that matters: each destructive handler calls
+//! `require_capability` before doing anything irreversible, so holding the
+//! right [`Capability`] is a precondition of the Rust side actually
+//! running the command, not just something the frontend is expected to
+//! check before it calls in.
+//!
+//! Granted capabilities live in a single process-wide set (see
+//! `granted_set`), seeded by build profile via `default_capabilities` -
+//! debug builds hold everything so local development isn't gated, release
+//! builds start with only [`Capability::ScanReadonly`]. The raw
+//! `grant_capability`/`revoke_capability` primitives are deliberately not
+//! exposed as `#[tauri::command]`s - a bare "grant me the capability"
+//! command would let a compromised webview unlock itself right back, and
+//! so would gating it on a caller-supplied confirmation string: that
+//! string would just be another IPC argument the same compromised webview
+//! could read out of this source and send straight back. Widening the set
+//! instead goes through [`elevate`], which only grants a capability once
+//! the OS itself has shown the user a native confirmation dialog (see
+//! `grant_capability_cmd` in `commands::acl`, which drives
+//! `tauri_plugin_dialog`'s blocking prompt before calling `elevate`) - a
+//! webview can script its own DOM, but it can't click a button rendered
+//! outside it. Every grant is also recorded to an on-disk audit log, the
+//! same audit-trail shape this app already uses for `ledger::approve_file`.
+//! [`list_granted_capabilities_cmd`] lets the UI reflect what's currently
+//! allowed without being able to change it.
+//!
+//! Some of the destructive commands named when this gate was requested -
+//! `kill_process_cmd`, `delete_multiple_ai_junk`,
+//! `delete_project_chat_history_cmd`, `uninstall_tool` - live in command
+//! modules (`services`, `ai_cleanup`, `chat_history`, `tools`) that aren't
+//! present in this checkout; `commands/mod.rs` declares them but their
+//! files are missing. They aren't gated here for that reason - adding the
+//! `require_capability` call to each is a one-line change once those files
+//! exist, following the pattern already applied to `uninstall_package`,
+//! `uninstall_ai_tool_cmd`/`uninstall_ai_tools_cmd`, and
+//! `apply_remediation_cmd`/`apply_all_remediations_cmd`.
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{OnceLock, RwLock};
+
+/// A named bundle of commands a frontend context may be granted, following
+/// Tauri's own capability terminology one level down from the IPC boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Capability {
+    /// Scans, listings, and other commands that don't change anything on disk.
+    ScanReadonly,
+    /// Killing or otherwise acting on a running process.
+    ProcessControl,
+    /// Irreversible deletes, uninstalls, or file rewrites - uninstalling a
+    /// package/tool, deleting cache or chat history, applying a security
+    /// remediation.
+    DestructiveDelete,
+}
+
+fn granted_set() -> &'static RwLock<HashSet<Capability>> {
+    static GRANTED: OnceLock<RwLock<HashSet<Capability>>> = OnceLock::new();
+    GRANTED.get_or_init(|| RwLock::new(default_capabilities()))
+}
+
+/// Capabilities held before anything is explicitly granted or revoked.
+/// Debug builds get every capability so local development isn't gated by
+/// this layer; release builds start read-only and the destructive ones
+/// have to be granted, e.g. once the frontend has its own confirmation
+/// flow for them.
+fn default_capabilities() -> HashSet<Capability> {
+    if cfg!(debug_assertions) {
+        HashSet::from([
+            Capability::ScanReadonly,
+            Capability::ProcessControl,
+            Capability::DestructiveDelete,
+        ])
+    } else {
+        HashSet::from([Capability::ScanReadonly])
+    }
+}
+
+/// Is `capability` currently granted?
+pub fn has_capability(capability: Capability) -> bool {
+    granted_set().read().is_ok_and(|g| g.contains(&capability))
+}
+
+/// Grant `capability` to the current process. Not exposed as a command
+/// directly - see `elevate`, which only calls this once the caller has
+/// already confirmed the grant with the user via a native dialog.
+fn grant_capability(capability: Capability) {
+    if let Ok(mut g) = granted_set().write() {
+        g.insert(capability);
+    }
+}
+
+/// Revoke `capability` from the current process. Narrowing what's granted
+/// needs no confirmation, so `revoke_capability_cmd` calls this directly.
+pub fn revoke_capability(capability: Capability) {
+    if let Ok(mut g) = granted_set().write() {
+        g.remove(&capability);
+    }
+    record_audit(capability, "revoked");
+}
+
+fn audit_log_path() -> Option<PathBuf> {
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()?;
+    Some(
+        PathBuf::from(home)
+            .join(".dev-janitor")
+            .join("capability_grants.log"),
+    )
+}
+
+/// Append a timestamped `action capability` line to the audit log, so a
+/// grant - the one thing this module lets a running app do for itself -
+/// leaves a record behind even if nothing else in the UI surfaces it. A
+/// missing home directory means no write at all, rather than falling back
+/// to whatever the process's current directory happens to be.
+fn record_audit(capability: Capability, action: &str) {
+    let Some(path) = audit_log_path() else {
+        return;
+    };
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    let line = format!(
+        "{} {} {:?}\n",
+        Local::now().format("%Y-%m-%d %H:%M:%S"),
+        action,
+        capability
+    );
+    if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(&path) {
+        let _ = file.write_all(line.as_bytes());
+    }
+}
+
+/// Grant `capability` and record it to the audit log. The caller -
+/// `grant_capability_cmd` - is responsible for having already confirmed
+/// this with the user via a native dialog; `elevate` itself trusts that
+/// confirmation happened rather than re-checking it, since anything it
+/// could check here (a token, a phrase) is just another value a
+/// compromised webview could supply itself. Errors, without touching the
+/// grant set or the audit log, if `capability` is already held - whether
+/// because it's in `default_capabilities` (e.g. `ScanReadonly` always, or
+/// every capability in a debug build) or because it was granted earlier -
+/// since there's nothing to elevate and nothing actually happened.
+pub fn elevate(capability: Capability) -> Result<(), String> {
+    if has_capability(capability) {
+        return Err("This capability is already granted".to_string());
+    }
+    grant_capability(capability);
+    record_audit(capability, "granted");
+    Ok(())
+}
+
+/// Guard for the top of a destructive command handler: `Ok(())` if
+/// `capability` is held, otherwise an error naming the missing capability
+/// so the frontend can surface why the action was refused.
+pub fn require_capability(capability: Capability) -> Result<(), String> {
+    if has_capability(capability) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Missing required capability `{:?}` - this action is disabled for the current frontend context",
+            capability
+        ))
+    }
+}
+
+/// Every capability currently granted, sorted for a stable UI order. Backs
+/// `list_granted_capabilities_cmd`.
+pub fn list_granted() -> Vec<Capability> {
+    let mut caps: Vec<Capability> = granted_set()
+        .read()
+        .map(|g| g.iter().copied().collect())
+        .unwrap_or_default();
+    caps.sort_by_key(|c| format!("{:?}", c));
+    caps
+}
@@ -0,0 +1,22 @@
+//! Supply-chain advisory scanning for installed cargo and pip packages
+//!
+//! Cross-references `PackageManager::list_packages()` output against the
+//! RustSec advisory database (cargo) and the OSV.dev batch API (pip),
+//! producing `SecurityFinding`s in the same shape the AI-tool scanner
+//! already emits so both surfaces render through one UI.
+
+pub mod osv;
+pub mod rustsec;
+
+use crate::package_manager::PackageInfo;
+use crate::security_scan::SecurityFinding;
+
+/// Scan installed cargo packages against the cached RustSec advisory-db
+pub fn scan_cargo_advisories(packages: &[PackageInfo]) -> Vec<SecurityFinding> {
+    rustsec::scan(packages)
+}
+
+/// Scan installed pip packages against OSV.dev
+pub fn scan_pip_advisories(packages: &[PackageInfo]) -> Vec<SecurityFinding> {
+    osv::scan(packages)
+}
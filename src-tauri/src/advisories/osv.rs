@@ -0,0 +1,226 @@
+//! OSV.dev advisory lookups for installed pip packages
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::package_manager::PackageInfo;
+use crate::security_scan::{base_score_from_vector, risk_level_from_cvss, RiskLevel, SecurityFinding};
+
+const OSV_BATCH_URL: &str = "https://api.osv.dev/v1/querybatch";
+const TTL_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Serialize)]
+struct OsvQuery {
+    package: OsvPackage,
+    version: String,
+}
+
+#[derive(Debug, Serialize)]
+struct OsvPackage {
+    name: String,
+    ecosystem: String,
+}
+
+#[derive(Debug, Serialize)]
+struct OsvBatchRequest {
+    queries: Vec<OsvQuery>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvBatchResponse {
+    #[serde(default)]
+    results: Vec<OsvResult>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OsvResult {
+    #[serde(default)]
+    vulns: Vec<OsvVuln>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvVuln {
+    id: String,
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    severity: Vec<OsvSeverity>,
+    #[serde(default)]
+    affected: Vec<OsvAffected>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvSeverity {
+    #[serde(rename = "type")]
+    kind: String,
+    score: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OsvAffected {
+    #[serde(default)]
+    ranges: Vec<OsvRange>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OsvRange {
+    #[serde(default)]
+    events: Vec<HashMap<String, String>>,
+}
+
+/// One finding already resolved to our own shape, persisted to disk so
+/// repeated scans within the TTL stay offline-capable
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedFinding {
+    package: String,
+    vuln_id: String,
+    summary: String,
+    cvss: Option<f32>,
+    fixed: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct OsvCache {
+    checked_at: u64,
+    findings: Vec<CachedFinding>,
+}
+
+fn cache_path() -> PathBuf {
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .unwrap_or_default();
+    PathBuf::from(home)
+        .join(".dev-janitor")
+        .join("cache")
+        .join("osv_pip.json")
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn load_cache() -> OsvCache {
+    fs::read_to_string(cache_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_cache(cache: &OsvCache) {
+    let path = cache_path();
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(json) = serde_json::to_string(cache) {
+        let _ = fs::write(path, json);
+    }
+}
+
+/// OSV severity entries carry a CVSS vector string rather than a bare base
+/// score, so derive it the same way `rustsec.rs` does (see
+/// `security_scan::cvss`) - NVD is the only source in this series that
+/// hands us an already-computed `baseScore`.
+fn parse_cvss_score(vector: &str) -> Option<f32> {
+    base_score_from_vector(vector)
+}
+
+fn extract_fixed_version(affected: &[OsvAffected]) -> Option<String> {
+    affected
+        .iter()
+        .find_map(|a| a.ranges.iter().find_map(|r| {
+            r.events.iter().find_map(|e| e.get("fixed").cloned())
+        }))
+}
+
+fn query_osv(queries: Vec<OsvQuery>) -> Option<OsvBatchResponse> {
+    let client = reqwest::blocking::Client::new();
+    client
+        .post(OSV_BATCH_URL)
+        .json(&OsvBatchRequest { queries })
+        .send()
+        .ok()?
+        .json()
+        .ok()
+}
+
+/// Scan installed pip packages against OSV.dev, reusing the disk cache when
+/// it's still within the TTL.
+pub fn scan(packages: &[PackageInfo]) -> Vec<SecurityFinding> {
+    let cache = load_cache();
+    let cache_fresh = now_secs().saturating_sub(cache.checked_at) < TTL_SECS;
+
+    let findings_data = if cache_fresh {
+        cache.findings
+    } else {
+        let queries: Vec<OsvQuery> = packages
+            .iter()
+            .map(|p| OsvQuery {
+                package: OsvPackage {
+                    name: p.name.clone(),
+                    ecosystem: "PyPI".to_string(),
+                },
+                version: p.version.clone(),
+            })
+            .collect();
+
+        if queries.is_empty() {
+            return Vec::new();
+        }
+
+        let response = match query_osv(queries) {
+            Some(r) => r,
+            None => return Vec::new(),
+        };
+
+        let collected: Vec<CachedFinding> = packages
+            .iter()
+            .zip(response.results.iter())
+            .flat_map(|(pkg, result)| {
+                result.vulns.iter().map(move |vuln| CachedFinding {
+                    package: pkg.name.clone(),
+                    vuln_id: vuln.id.clone(),
+                    summary: vuln.summary.clone(),
+                    cvss: vuln
+                        .severity
+                        .iter()
+                        .find(|s| s.kind == "CVSS_V3")
+                        .and_then(|s| parse_cvss_score(&s.score)),
+                    fixed: extract_fixed_version(&vuln.affected),
+                })
+            })
+            .collect();
+
+        save_cache(&OsvCache {
+            checked_at: now_secs(),
+            findings: collected.clone(),
+        });
+
+        collected
+    };
+
+    findings_data
+        .into_iter()
+        .map(|f| {
+            let risk_level = f.cvss.map(risk_level_from_cvss).unwrap_or(RiskLevel::Medium);
+            SecurityFinding {
+                tool_id: "pip".to_string(),
+                tool_name: format!("pip: {}", f.package),
+                issue: f.vuln_id.clone(),
+                description: f.summary.clone(),
+                risk_level,
+                remediation: f
+                    .fixed
+                    .map(|v| format!("Upgrade to {}", v))
+                    .unwrap_or_else(|| "No fixed version published yet".to_string()),
+                details: format!("OSV advisory {}", f.vuln_id),
+                remediation_action: None,
+            }
+        })
+        .collect()
+}
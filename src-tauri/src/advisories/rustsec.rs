@@ -0,0 +1,191 @@
+//! RustSec advisory-db lookups for installed cargo packages
+//!
+//! The advisory-db is a git repo of per-crate TOML files, each listing the
+//! version ranges that are `patched` or `unaffected`. We keep a shallow
+//! clone under the cache dir and refresh it once the TTL expires, so a
+//! normal scan never needs the network.
+
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::package_manager::PackageInfo;
+use crate::security_scan::{base_score_from_vector, risk_level_from_cvss, RiskLevel, SecurityFinding};
+
+const ADVISORY_DB_REPO: &str = "https://github.com/RustSec/advisory-db";
+const TTL_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Deserialize)]
+struct Advisory {
+    advisory: AdvisoryMeta,
+    #[serde(default)]
+    versions: AdvisoryVersions,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdvisoryMeta {
+    id: String,
+    package: String,
+    title: String,
+    /// CVSS v3 vector string, when the advisory records one (e.g.
+    /// `"CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"`) - advisory-db TOML
+    /// encodes this field as the vector, not a bare score, so the base
+    /// score has to be derived from it (see `security_scan::cvss`).
+    #[serde(default)]
+    cvss: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct AdvisoryVersions {
+    #[serde(default)]
+    patched: Vec<String>,
+    #[serde(default)]
+    unaffected: Vec<String>,
+}
+
+fn cache_dir() -> PathBuf {
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .unwrap_or_default();
+    PathBuf::from(home)
+        .join(".dev-janitor")
+        .join("cache")
+        .join("advisory-db")
+}
+
+/// Clone (or fast-forward update) the RustSec advisory-db into the local
+/// cache. Falls back to whatever is already on disk if the network is
+/// unavailable, so scans stay offline-capable after the first fetch.
+fn ensure_advisory_db() -> Option<PathBuf> {
+    let dir = cache_dir();
+    let marker = dir.join(".last_fetch");
+
+    let is_stale = fs::metadata(&marker)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.elapsed().ok())
+        .map(|age| age.as_secs() > TTL_SECS)
+        .unwrap_or(true);
+
+    if !is_stale && dir.join("crates").is_dir() {
+        return Some(dir);
+    }
+
+    fs::create_dir_all(&dir).ok()?;
+
+    let status = if dir.join(".git").is_dir() {
+        std::process::Command::new("git")
+            .args(["-C", &dir.to_string_lossy(), "pull", "--ff-only"])
+            .status()
+    } else {
+        std::process::Command::new("git")
+            .args([
+                "clone",
+                "--depth",
+                "1",
+                ADVISORY_DB_REPO,
+                &dir.to_string_lossy(),
+            ])
+            .status()
+    };
+
+    match status {
+        Ok(s) if s.success() => {
+            let _ = fs::write(&marker, b"");
+            Some(dir)
+        }
+        _ if dir.join("crates").is_dir() => Some(dir),
+        _ => None,
+    }
+}
+
+fn load_advisories_for(db_dir: &Path, crate_name: &str) -> Vec<Advisory> {
+    let crate_dir = db_dir.join("crates").join(crate_name);
+    let mut advisories = Vec::new();
+
+    if let Ok(entries) = fs::read_dir(&crate_dir) {
+        for entry in entries.flatten() {
+            if entry.path().extension().and_then(|e| e.to_str()) != Some("toml") {
+                continue;
+            }
+            if let Ok(content) = fs::read_to_string(entry.path()) {
+                if let Ok(advisory) = toml::from_str::<Advisory>(&content) {
+                    advisories.push(advisory);
+                }
+            }
+        }
+    }
+
+    advisories
+}
+
+fn is_covered_by(installed: &Version, ranges: &[String]) -> bool {
+    ranges.iter().any(|req| {
+        VersionReq::parse(req)
+            .map(|r| r.matches(installed))
+            .unwrap_or(false)
+    })
+}
+
+/// Scan installed cargo packages against the cached RustSec advisory-db,
+/// reporting a finding for every `(name, version)` not covered by a
+/// `patched` or `unaffected` range.
+pub fn scan(packages: &[PackageInfo]) -> Vec<SecurityFinding> {
+    let mut findings = Vec::new();
+
+    let db_dir = match ensure_advisory_db() {
+        Some(d) => d,
+        None => return findings,
+    };
+
+    for pkg in packages {
+        let installed = match Version::parse(&pkg.version) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        for advisory in load_advisories_for(&db_dir, &pkg.name) {
+            let safe = is_covered_by(&installed, &advisory.versions.patched)
+                || is_covered_by(&installed, &advisory.versions.unaffected);
+            if safe {
+                continue;
+            }
+
+            let risk_level = advisory
+                .advisory
+                .cvss
+                .as_deref()
+                .and_then(base_score_from_vector)
+                .map(risk_level_from_cvss)
+                .unwrap_or(RiskLevel::Medium);
+            let remediation = advisory
+                .versions
+                .patched
+                .first()
+                .map(|v| format!("Upgrade to {}", v))
+                .unwrap_or_else(|| {
+                    "No patched version published yet - consider removing the package".to_string()
+                });
+
+            findings.push(SecurityFinding {
+                tool_id: "cargo".to_string(),
+                tool_name: format!("cargo: {}", pkg.name),
+                issue: advisory.advisory.title.clone(),
+                description: format!(
+                    "{} {} is affected by {}",
+                    pkg.name, pkg.version, advisory.advisory.id
+                ),
+                risk_level,
+                remediation,
+                details: format!(
+                    "Advisory {} (crate {})",
+                    advisory.advisory.id, advisory.advisory.package
+                ),
+                remediation_action: None,
+            });
+        }
+    }
+
+    findings
+}
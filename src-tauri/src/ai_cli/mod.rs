@@ -20,6 +20,9 @@ pub struct AiCliTool {
     pub uninstall_command: String,
     pub docs_url: String,
     pub config_paths: Vec<AiConfigFile>,
+    /// Absolute path to the resolved binary/shim, when it could be found in
+    /// one of the known per-user install roots rather than trusted to PATH
+    pub resolved_path: Option<String>,
 }
 
 /// Represents a config file for an AI CLI tool
@@ -44,6 +47,7 @@ pub fn get_ai_cli_tools() -> Vec<AiCliTool> {
             uninstall_command: "npm uninstall -g @anthropic-ai/claude-code".to_string(),
             docs_url: "https://docs.anthropic.com/claude-code".to_string(),
             config_paths: find_config_files("claude"),
+            resolved_path: None,
         }),
         check_tool(AiCliTool {
             id: "codex".to_string(),
@@ -56,6 +60,7 @@ pub fn get_ai_cli_tools() -> Vec<AiCliTool> {
             uninstall_command: "npm uninstall -g @openai/codex".to_string(),
             docs_url: "https://github.com/openai/codex".to_string(),
             config_paths: find_config_files("codex"),
+            resolved_path: None,
         }),
         check_tool(AiCliTool {
             id: "gemini".to_string(),
@@ -68,6 +73,7 @@ pub fn get_ai_cli_tools() -> Vec<AiCliTool> {
             uninstall_command: "npm uninstall -g @google/gemini-cli".to_string(),
             docs_url: "https://ai.google.dev/gemini-api/docs".to_string(),
             config_paths: find_config_files("gemini"),
+            resolved_path: None,
         }),
         check_tool(AiCliTool {
             id: "aider".to_string(),
@@ -80,6 +86,7 @@ pub fn get_ai_cli_tools() -> Vec<AiCliTool> {
             uninstall_command: "pipx uninstall aider-chat".to_string(),
             docs_url: "https://aider.chat".to_string(),
             config_paths: find_config_files("aider"),
+            resolved_path: None,
         }),
         check_tool(AiCliTool {
             id: "continue".to_string(),
@@ -92,6 +99,7 @@ pub fn get_ai_cli_tools() -> Vec<AiCliTool> {
             uninstall_command: "npm uninstall -g continue".to_string(),
             docs_url: "https://continue.dev".to_string(),
             config_paths: find_config_files("continue"),
+            resolved_path: None,
         }),
         check_tool(AiCliTool {
             id: "cody".to_string(),
@@ -104,6 +112,7 @@ pub fn get_ai_cli_tools() -> Vec<AiCliTool> {
             uninstall_command: "npm uninstall -g @sourcegraph/cody".to_string(),
             docs_url: "https://sourcegraph.com/cody".to_string(),
             config_paths: find_config_files("cody"),
+            resolved_path: None,
         }),
         check_tool(AiCliTool {
             id: "cursor".to_string(),
@@ -116,12 +125,13 @@ pub fn get_ai_cli_tools() -> Vec<AiCliTool> {
             uninstall_command: "Manual uninstall required".to_string(),
             docs_url: "https://cursor.sh".to_string(),
             config_paths: find_config_files("cursor"),
+            resolved_path: None,
         }),
     ]
 }
 
 /// Find config files for an AI CLI tool
-fn find_config_files(tool_id: &str) -> Vec<AiConfigFile> {
+pub(crate) fn find_config_files(tool_id: &str) -> Vec<AiConfigFile> {
     let home = env::var("HOME")
         .or_else(|_| env::var("USERPROFILE"))
         .unwrap_or_default();
@@ -217,16 +227,92 @@ fn check_tool(mut tool: AiCliTool) -> AiCliTool {
         _ => return tool,
     };
 
-    if let Some(version) = run_command_get_version(cmd, &args) {
+    let resolved = resolve_tool_path(cmd);
+
+    // Try the bare command on PATH first; on Windows the npm/pipx shim
+    // often isn't on PATH at all, so fall back to the resolved install root
+    let version = run_command_get_version(cmd, &args).or_else(|| {
+        resolved
+            .as_ref()
+            .and_then(|path| run_command_get_version(&path.to_string_lossy(), &args))
+    });
+
+    if let Some(version) = version {
         tool.installed = true;
         tool.version = Some(version);
     }
 
+    tool.resolved_path = resolved.map(|p| p.to_string_lossy().to_string());
+
     tool
 }
 
+/// Per-user install roots where npm/pipx shims commonly live, searched
+/// before shelling out via a bare command name on PATH. This is what lets
+/// Dev Janitor find a tool even when its shim isn't on PATH.
+fn known_install_roots() -> Vec<PathBuf> {
+    let home = env::var("HOME")
+        .or_else(|_| env::var("USERPROFILE"))
+        .unwrap_or_default();
+
+    let mut roots = Vec::new();
+
+    if let Ok(app_data) = env::var("APPDATA") {
+        roots.push(PathBuf::from(app_data).join("npm"));
+    }
+    if let Ok(local_app_data) = env::var("LOCALAPPDATA") {
+        roots.push(PathBuf::from(local_app_data));
+    }
+    if let Ok(pipx_bin) = env::var("PIPX_BIN_DIR") {
+        roots.push(PathBuf::from(pipx_bin));
+    }
+    roots.push(PathBuf::from(&home).join(".local").join("bin"));
+
+    roots
+}
+
+/// Candidate shim file names for a command: Windows installs `.cmd`/`.exe`/`.ps1`
+/// shims rather than a bare executable, while Unix just uses the raw name.
+fn shim_candidates(cmd: &str) -> Vec<String> {
+    #[cfg(target_os = "windows")]
+    {
+        vec![
+            format!("{}.cmd", cmd),
+            format!("{}.exe", cmd),
+            format!("{}.ps1", cmd),
+        ]
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        vec![cmd.to_string()]
+    }
+}
+
+/// Resolve a tool's absolute binary/shim path by searching known install
+/// roots, rather than trusting whatever `PATH` happens to find first.
+fn resolve_tool_path(cmd: &str) -> Option<PathBuf> {
+    for root in known_install_roots() {
+        for name in shim_candidates(cmd) {
+            let candidate = root.join(&name);
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+        }
+    }
+    None
+}
+
+/// Compare two tools by their resolved install path rather than by `PATH`
+/// ordering, so a rename/relocation of the shim is detected as a real change.
+pub fn same_resolved_binary(a: &AiCliTool, b: &AiCliTool) -> bool {
+    match (&a.resolved_path, &b.resolved_path) {
+        (Some(x), Some(y)) => x == y,
+        _ => false,
+    }
+}
+
 /// Run a command and extract version
-fn run_command_get_version(cmd: &str, args: &[&str]) -> Option<String> {
+pub(crate) fn run_command_get_version(cmd: &str, args: &[&str]) -> Option<String> {
     // On Windows, .cmd files (npm scripts) need to be run through cmd /c
     #[cfg(target_os = "windows")]
     let output = {
@@ -260,6 +346,78 @@ fn run_command_get_version(cmd: &str, args: &[&str]) -> Option<String> {
     None
 }
 
+/// Which batch operation to run against a set of tool ids
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BatchOp {
+    Install,
+    Update,
+    Uninstall,
+}
+
+/// Outcome of a single tool within a batch install/update/uninstall run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResult {
+    pub tool_id: String,
+    pub status: String, // "ok" or "error"
+    pub output: String,
+}
+
+/// Resolve the tool ids a batch operation should run against: the explicit
+/// list if given, or every currently installed tool when `all` is set.
+fn resolve_batch_targets(tool_ids: Vec<String>, all: bool) -> Vec<String> {
+    if all {
+        get_ai_cli_tools()
+            .into_iter()
+            .filter(|t| t.installed)
+            .map(|t| t.id)
+            .collect()
+    } else {
+        tool_ids
+    }
+}
+
+/// Run a batch operation across a set of tool ids, collecting a per-tool
+/// result instead of failing the whole operation on the first error.
+fn run_batch(tool_ids: Vec<String>, all: bool, op: BatchOp) -> Vec<BatchResult> {
+    resolve_batch_targets(tool_ids, all)
+        .into_iter()
+        .map(|tool_id| {
+            let result = match op {
+                BatchOp::Install => install_ai_tool(&tool_id),
+                BatchOp::Update => update_ai_tool(&tool_id),
+                BatchOp::Uninstall => uninstall_ai_tool(&tool_id),
+            };
+            match result {
+                Ok(output) => BatchResult {
+                    tool_id,
+                    status: "ok".to_string(),
+                    output,
+                },
+                Err(output) => BatchResult {
+                    tool_id,
+                    status: "error".to_string(),
+                    output,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Install multiple AI CLI tools (or every installed tool when `all` is set)
+pub fn install_ai_tools(tool_ids: Vec<String>, all: bool) -> Vec<BatchResult> {
+    run_batch(tool_ids, all, BatchOp::Install)
+}
+
+/// Update multiple AI CLI tools (or every installed tool when `all` is set)
+pub fn update_ai_tools(tool_ids: Vec<String>, all: bool) -> Vec<BatchResult> {
+    run_batch(tool_ids, all, BatchOp::Update)
+}
+
+/// Uninstall multiple AI CLI tools (or every installed tool when `all` is set)
+pub fn uninstall_ai_tools(tool_ids: Vec<String>, all: bool) -> Vec<BatchResult> {
+    run_batch(tool_ids, all, BatchOp::Uninstall)
+}
+
 /// Install an AI CLI tool
 pub fn install_ai_tool(tool_id: &str) -> Result<String, String> {
     let tools = get_ai_cli_tools();
@@ -278,7 +436,24 @@ pub fn install_ai_tool(tool_id: &str) -> Result<String, String> {
     run_install_command(&tool.install_command)
 }
 
-/// Update an AI CLI tool
+/// The package manager and package name backing an AI CLI tool's install, so
+/// update/uninstall can be routed through the real manager instead of a
+/// hardcoded command string. Tools with no backend (e.g. manual-download
+/// Cursor) return `None` and keep using their raw `*_command` strings.
+fn backend_package(tool_id: &str) -> Option<(&'static str, &'static str)> {
+    match tool_id {
+        "claude" => Some(("npm", "@anthropic-ai/claude-code")),
+        "codex" => Some(("npm", "@openai/codex")),
+        "gemini" => Some(("npm", "@google/gemini-cli")),
+        "aider" => Some(("pipx", "aider-chat")),
+        "continue" => Some(("npm", "continue")),
+        "cody" => Some(("npm", "@sourcegraph/cody")),
+        _ => None,
+    }
+}
+
+/// Update an AI CLI tool, routing through its backend package manager when
+/// one is known rather than re-running the raw update command string.
 pub fn update_ai_tool(tool_id: &str) -> Result<String, String> {
     let tools = get_ai_cli_tools();
     let tool = tools
@@ -286,10 +461,23 @@ pub fn update_ai_tool(tool_id: &str) -> Result<String, String> {
         .find(|t| t.id == tool_id)
         .ok_or_else(|| format!("Tool not found: {}", tool_id))?;
 
+    if let Some((manager_name, package)) = backend_package(tool_id) {
+        if let Some(manager) = crate::package_manager::discover_managers()
+            .into_iter()
+            .find(|m| m.name() == manager_name)
+        {
+            // This is the per-tool worker behind `update_ai_tools`'s batch/"update
+            // all" path, so major bumps stay gated the same as a bulk package
+            // update - a per-tool error just surfaces in that tool's BatchResult.
+            return manager.update_package(package, false);
+        }
+    }
+
     run_install_command(&tool.update_command)
 }
 
-/// Uninstall an AI CLI tool
+/// Uninstall an AI CLI tool, routing through its backend package manager when
+/// one is known rather than re-running the raw uninstall command string.
 pub fn uninstall_ai_tool(tool_id: &str) -> Result<String, String> {
     let tools = get_ai_cli_tools();
     let tool = tools
@@ -301,6 +489,15 @@ pub fn uninstall_ai_tool(tool_id: &str) -> Result<String, String> {
         return Err(format!("{} requires manual uninstallation", tool.name));
     }
 
+    if let Some((manager_name, package)) = backend_package(tool_id) {
+        if let Some(manager) = crate::package_manager::discover_managers()
+            .into_iter()
+            .find(|m| m.name() == manager_name)
+        {
+            return manager.uninstall_package(package);
+        }
+    }
+
     run_install_command(&tool.uninstall_command)
 }
 
@@ -326,3 +523,269 @@ fn run_install_command(command: &str) -> Result<String, String> {
         Err(e) => Err(format!("Failed to run command: {}", e)),
     }
 }
+
+/// Which stream a progress line came from
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// One line of streamed install/update/uninstall output, emitted as it arrives
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiToolProgressEvent {
+    pub tool_id: String,
+    pub line: String,
+    pub stream: OutputStream,
+}
+
+/// Emitted once the streamed command has finished
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiToolProgressDone {
+    pub tool_id: String,
+    pub success: bool,
+    pub output: String,
+}
+
+const PROGRESS_EVENT: &str = "ai-tool-progress";
+const PROGRESS_DONE_EVENT: &str = "ai-tool-progress-done";
+
+/// Run a shell command, streaming each output line to the frontend as an
+/// `ai-tool-progress` event instead of buffering the whole process like
+/// `run_install_command` does. Long-running installs (`npm install -g`,
+/// `pipx install`) no longer appear to hang the UI.
+async fn run_install_command_streaming(
+    app: &tauri::AppHandle,
+    tool_id: &str,
+    command: &str,
+) -> Result<String, String> {
+    use tauri::Emitter;
+    use tokio::io::{AsyncBufReadExt, BufReader};
+    use tokio::process::Command;
+
+    #[cfg(target_os = "windows")]
+    let mut child = {
+        let mut c = Command::new("cmd");
+        c.args(["/C", command]);
+        c
+    }
+    .stdout(std::process::Stdio::piped())
+    .stderr(std::process::Stdio::piped())
+    .spawn()
+    .map_err(|e| format!("Failed to spawn command: {}", e))?;
+
+    #[cfg(not(target_os = "windows"))]
+    let mut child = Command::new("sh")
+        .args(["-c", command])
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to spawn command: {}", e))?;
+
+    let stdout = child.stdout.take().expect("piped stdout");
+    let stderr = child.stderr.take().expect("piped stderr");
+
+    let mut collected = String::new();
+
+    let mut stdout_lines = BufReader::new(stdout).lines();
+    let mut stderr_lines = BufReader::new(stderr).lines();
+
+    // Track each stream's EOF/error state separately and stop polling it once
+    // done, only exiting the loop when both are finished - `select!` without
+    // this would busy-spin whichever stream hits EOF first (its `next_line()`
+    // future resolves to `Ok(None)` immediately on every poll), and exiting
+    // the loop on the first EOF would silently drop any output still
+    // buffered on the other stream.
+    let mut stdout_done = false;
+    let mut stderr_done = false;
+
+    while !stdout_done || !stderr_done {
+        tokio::select! {
+            line = stdout_lines.next_line(), if !stdout_done => {
+                match line {
+                    Ok(Some(line)) => {
+                        collected.push_str(&line);
+                        collected.push('\n');
+                        let _ = app.emit(PROGRESS_EVENT, AiToolProgressEvent {
+                            tool_id: tool_id.to_string(),
+                            line,
+                            stream: OutputStream::Stdout,
+                        });
+                    }
+                    Ok(None) | Err(_) => stdout_done = true,
+                }
+            }
+            line = stderr_lines.next_line(), if !stderr_done => {
+                match line {
+                    Ok(Some(line)) => {
+                        collected.push_str(&line);
+                        collected.push('\n');
+                        let _ = app.emit(PROGRESS_EVENT, AiToolProgressEvent {
+                            tool_id: tool_id.to_string(),
+                            line,
+                            stream: OutputStream::Stderr,
+                        });
+                    }
+                    Ok(None) | Err(_) => stderr_done = true,
+                }
+            }
+        }
+    }
+
+    let status = child
+        .wait()
+        .await
+        .map_err(|e| format!("Failed to wait for command: {}", e))?;
+
+    let _ = app.emit(
+        PROGRESS_DONE_EVENT,
+        AiToolProgressDone {
+            tool_id: tool_id.to_string(),
+            success: status.success(),
+            output: collected.clone(),
+        },
+    );
+
+    if status.success() {
+        Ok(collected)
+    } else {
+        Err(collected)
+    }
+}
+
+/// Run a `PackageManager::update_package`/`uninstall_package` call on a
+/// blocking thread, emitting its output through the same `ai-tool-progress`/
+/// `ai-tool-progress-done` events `run_install_command_streaming` uses - so
+/// the streaming commands route through `backend_package` exactly like
+/// `update_ai_tool`/`uninstall_ai_tool` already do, instead of always
+/// falling back to the tool's raw hardcoded command string. `None` if
+/// `manager_name` isn't actually discovered on this machine, so the caller
+/// can fall back to the raw command the same way `update_ai_tool`/
+/// `uninstall_ai_tool` do. There's no incremental stdout/stderr to stream
+/// here, since the backend call is a single blocking function that returns
+/// all its output at once - each of its lines is emitted as its own
+/// `ai-tool-progress` event, same as a genuinely streamed command, rather
+/// than one event with embedded newlines.
+async fn run_backend_streaming(
+    app: &tauri::AppHandle,
+    tool_id: &str,
+    manager_name: &'static str,
+    package: &'static str,
+    action: BackendAction,
+) -> Option<Result<String, String>> {
+    use tauri::Emitter;
+
+    let manager = crate::package_manager::discover_managers()
+        .into_iter()
+        .find(|m| m.name() == manager_name)?;
+
+    let result = tauri::async_runtime::spawn_blocking(move || match action {
+        BackendAction::Update => manager.update_package(package, false),
+        BackendAction::Uninstall => manager.uninstall_package(package),
+    })
+    .await
+    .unwrap_or_else(|e| Err(format!("Backend task failed: {}", e)));
+
+    let (stream, output) = match &result {
+        Ok(output) => (OutputStream::Stdout, output),
+        Err(error) => (OutputStream::Stderr, error),
+    };
+    for line in output.lines() {
+        let _ = app.emit(
+            PROGRESS_EVENT,
+            AiToolProgressEvent { tool_id: tool_id.to_string(), line: line.to_string(), stream },
+        );
+    }
+    let _ = app.emit(
+        PROGRESS_DONE_EVENT,
+        AiToolProgressDone {
+            tool_id: tool_id.to_string(),
+            success: result.is_ok(),
+            output: result.clone().unwrap_or_else(|e| e),
+        },
+    );
+
+    Some(result)
+}
+
+/// Which `PackageManager` call `run_backend_streaming` should make.
+#[derive(Debug, Clone, Copy)]
+enum BackendAction {
+    Update,
+    Uninstall,
+}
+
+/// Install an AI CLI tool, streaming output via `ai-tool-progress` events
+pub async fn install_ai_tool_streaming(
+    app: &tauri::AppHandle,
+    tool_id: &str,
+) -> Result<String, String> {
+    let tools = get_ai_cli_tools();
+    let tool = tools
+        .iter()
+        .find(|t| t.id == tool_id)
+        .ok_or_else(|| format!("Tool not found: {}", tool_id))?;
+
+    if tool.install_command.starts_with("Download") {
+        return Err(format!(
+            "{} requires manual installation. Visit: {}",
+            tool.name, tool.docs_url
+        ));
+    }
+
+    run_install_command_streaming(app, tool_id, &tool.install_command).await
+}
+
+/// Update an AI CLI tool, streaming output via `ai-tool-progress` events.
+/// Routes through `backend_package` when one is known, same as the
+/// non-streaming `update_ai_tool`, rather than always re-running the tool's
+/// raw `update_command` string.
+pub async fn update_ai_tool_streaming(
+    app: &tauri::AppHandle,
+    tool_id: &str,
+) -> Result<String, String> {
+    let tools = get_ai_cli_tools();
+    let tool = tools
+        .iter()
+        .find(|t| t.id == tool_id)
+        .ok_or_else(|| format!("Tool not found: {}", tool_id))?;
+
+    if let Some((manager_name, package)) = backend_package(tool_id) {
+        if let Some(result) =
+            run_backend_streaming(app, tool_id, manager_name, package, BackendAction::Update).await
+        {
+            return result;
+        }
+    }
+
+    run_install_command_streaming(app, tool_id, &tool.update_command).await
+}
+
+/// Uninstall an AI CLI tool, streaming output via `ai-tool-progress` events.
+/// Routes through `backend_package` when one is known, same as the
+/// non-streaming `uninstall_ai_tool`, rather than always re-running the
+/// tool's raw `uninstall_command` string.
+pub async fn uninstall_ai_tool_streaming(
+    app: &tauri::AppHandle,
+    tool_id: &str,
+) -> Result<String, String> {
+    let tools = get_ai_cli_tools();
+    let tool = tools
+        .iter()
+        .find(|t| t.id == tool_id)
+        .ok_or_else(|| format!("Tool not found: {}", tool_id))?;
+
+    if tool.uninstall_command.contains("Manual") {
+        return Err(format!("{} requires manual uninstallation", tool.name));
+    }
+
+    if let Some((manager_name, package)) = backend_package(tool_id) {
+        if let Some(result) =
+            run_backend_streaming(app, tool_id, manager_name, package, BackendAction::Uninstall).await
+        {
+            return result;
+        }
+    }
+
+    run_install_command_streaming(app, tool_id, &tool.uninstall_command).await
+}
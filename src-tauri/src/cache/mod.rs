@@ -1,12 +1,30 @@
 //! Cache scanning and cleaning module for Dev Janitor v2
 //! Supports 11+ package manager caches and project caches
 
+use crossbeam_channel::Sender;
+use ignore::WalkBuilder;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
 use walkdir::WalkDir;
 
+/// Progress update emitted periodically during a cancellable directory walk -
+/// lets a GUI/CLI show a live counter while `get_dir_size_cancellable` or
+/// `scan_project_caches_cancellable` is still running.
+#[derive(Debug, Clone)]
+pub struct ScanProgress {
+    pub entries_seen: usize,
+    pub bytes_counted: u64,
+    pub current_path: String,
+}
+
+/// How often (in WalkDir entries) a cancellable walk emits a `ScanProgress`.
+const PROGRESS_INTERVAL: usize = 512;
+
 /// Represents a cache entry that can be cleaned
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheInfo {
@@ -16,6 +34,9 @@ pub struct CacheInfo {
     pub size: u64,
     pub size_display: String,
     pub cache_type: String, // "package_manager" or "project"
+    /// Most recent file modification time under this cache, as a Unix
+    /// timestamp - what `prune_caches` measures staleness against.
+    pub last_used: u64,
 }
 
 /// Format bytes to human readable string
@@ -37,139 +58,342 @@ pub fn format_size(bytes: u64) -> String {
 
 /// Calculate directory size recursively
 pub fn get_dir_size(path: &PathBuf) -> u64 {
-    WalkDir::new(path)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter_map(|e| e.metadata().ok())
-        .filter(|m| m.is_file())
-        .map(|m| m.len())
-        .sum()
+    scan_dir(path).0
+}
+
+/// Walk a directory once, returning both its total size and the most recent
+/// file modification time (as a Unix timestamp) found within it. Callers that
+/// need both - `scan_package_manager_caches`, `scan_project_caches` - get the
+/// mtime for free off the same WalkDir pass `get_dir_size` already paid for.
+fn scan_dir(path: &PathBuf) -> (u64, u64) {
+    scan_dir_cancellable(path, &AtomicBool::new(false), None, &mut ProgressCounters::default())
+}
+
+/// Cancellable, progress-reporting variant of `get_dir_size` for trees that
+/// can run to millions of files (a deep `node_modules`/`target`). `stop` is
+/// checked every WalkDir iteration so a caller can abort mid-walk; `progress`
+/// receives a `ScanProgress` every `PROGRESS_INTERVAL` entries. A stop leaves
+/// the walk incomplete but still returns the size/mtime accumulated up to
+/// that point - a partial total is still worth showing after a cancel.
+pub fn get_dir_size_cancellable(
+    path: &PathBuf,
+    stop: &AtomicBool,
+    progress: Option<&Sender<ScanProgress>>,
+) -> u64 {
+    scan_dir_cancellable(path, stop, progress, &mut ProgressCounters::default()).0
+}
+
+/// Running totals for a `ScanProgress` stream, threaded by `&mut` through
+/// nested `scan_dir_cancellable` calls (e.g. `scan_project_caches_cancellable`
+/// calling into it once per matched cache directory) so the reported counts
+/// keep climbing across the whole walk instead of resetting every time the
+/// walk descends into a new subtree.
+#[derive(Default)]
+struct ProgressCounters {
+    entries_seen: usize,
+    bytes_counted: u64,
+}
+
+fn scan_dir_cancellable(
+    path: &PathBuf,
+    stop: &AtomicBool,
+    progress: Option<&Sender<ScanProgress>>,
+    counters: &mut ProgressCounters,
+) -> (u64, u64) {
+    let mut size = 0u64;
+    let mut last_used = 0u64;
+
+    for entry in WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
+        if stop.load(Ordering::Relaxed) {
+            break;
+        }
+
+        if let Ok(metadata) = entry.metadata() {
+            if metadata.is_file() {
+                size += metadata.len();
+                counters.bytes_counted += metadata.len();
+                if let Ok(modified) = metadata.modified() {
+                    let secs = modified
+                        .duration_since(UNIX_EPOCH)
+                        .map(|d| d.as_secs())
+                        .unwrap_or(0);
+                    last_used = last_used.max(secs);
+                }
+            }
+        }
+
+        counters.entries_seen += 1;
+        if counters.entries_seen % PROGRESS_INTERVAL == 0 {
+            if let Some(sender) = progress {
+                let _ = sender.try_send(ScanProgress {
+                    entries_seen: counters.entries_seen,
+                    bytes_counted: counters.bytes_counted,
+                    current_path: entry.path().display().to_string(),
+                });
+            }
+        }
+    }
+
+    (size, last_used)
+}
+
+/// One cache definition as it appears under `[[cache]]` in the user config
+/// file, or as one of the built-ins `default_cache_config` generates. Unlike
+/// `CacheInfo`, this is the pre-scan description of *where to look*, not a
+/// scan result.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CacheDefinition {
+    pub id: String,
+    pub name: String,
+    /// Candidate locations, tried in order - the first that exists is
+    /// scanned, same as the old hardcoded per-OS path lists.
+    pub paths: Vec<PathBuf>,
+    pub cache_type: String,
+}
+
+/// The full set of cache definitions a scan should look for - the built-ins
+/// plus anything the user added in their config file.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct CacheConfig {
+    #[serde(rename = "cache", default)]
+    pub definitions: Vec<CacheDefinition>,
+}
+
+/// Resolve an environment variable to a path, falling back to `default` when
+/// it's unset - lets a relocated `CARGO_HOME`/`PNPM_HOME`/`GOPATH`/etc. be
+/// picked up instead of always assuming the stock `$HOME/...` layout.
+fn env_path_or(var: &str, default: PathBuf) -> PathBuf {
+    std::env::var(var)
+        .ok()
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or(default)
+}
+
+/// The current user's home directory, preferring `$HOME` with `%USERPROFILE%`
+/// as the Windows fallback - the one place this lookup happens, so
+/// `default_cache_config`, `user_config_path`, and `is_protected_path` can't
+/// drift out of sync on how it's resolved.
+fn home_dir() -> PathBuf {
+    PathBuf::from(
+        std::env::var("HOME")
+            .or_else(|_| std::env::var("USERPROFILE"))
+            .unwrap_or_default(),
+    )
 }
 
-/// Get package manager cache paths
-fn get_package_manager_caches() -> Vec<(&'static str, &'static str, Vec<PathBuf>)> {
+/// The package manager caches Dev Janitor knows about out of the box. Paths
+/// prefer each tool's own relocation variable (`CARGO_HOME`, `npm_config_cache`,
+/// `PNPM_HOME`, `GOPATH`, `SCOOP_CACHE`) over the stock `$HOME`-relative
+/// default, so a custom setup doesn't read as "no cache found".
+fn default_cache_config() -> CacheConfig {
     use std::env;
 
-    let home = env::var("HOME")
-        .or_else(|_| env::var("USERPROFILE"))
-        .unwrap_or_default();
+    let home = home_dir().to_string_lossy().to_string();
     let local_app_data = env::var("LOCALAPPDATA").unwrap_or_default();
     let app_data = env::var("APPDATA").unwrap_or_default();
 
-    vec![
-        // npm
-        (
-            "npm",
-            "npm Cache",
-            vec![
-                PathBuf::from(format!("{}/.npm", home)),
+    let definitions = vec![
+        CacheDefinition {
+            id: "npm".to_string(),
+            name: "npm Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![
+                env_path_or("npm_config_cache", PathBuf::from(format!("{}/.npm", home))),
                 PathBuf::from(format!("{}/npm-cache", local_app_data)),
             ],
-        ),
-        // yarn
-        (
-            "yarn",
-            "Yarn Cache",
-            vec![
+        },
+        CacheDefinition {
+            id: "yarn".to_string(),
+            name: "Yarn Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![
                 PathBuf::from(format!("{}/.yarn/cache", home)),
                 PathBuf::from(format!("{}/Yarn/Cache", local_app_data)),
             ],
-        ),
-        // pnpm
-        (
-            "pnpm",
-            "pnpm Cache",
-            vec![
-                PathBuf::from(format!("{}/.pnpm-store", home)),
+        },
+        CacheDefinition {
+            id: "pnpm".to_string(),
+            name: "pnpm Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![
+                // PNPM_HOME points at pnpm's install root (the binary, global
+                // packages, ...) - the actual content-addressable cache lives
+                // under its `store` subdirectory, not PNPM_HOME itself.
+                std::env::var("PNPM_HOME")
+                    .ok()
+                    .filter(|v| !v.is_empty())
+                    .map(|pnpm_home| PathBuf::from(pnpm_home).join("store"))
+                    .unwrap_or_else(|| PathBuf::from(format!("{}/.pnpm-store", home))),
                 PathBuf::from(format!("{}/pnpm/store", local_app_data)),
             ],
-        ),
-        // pip
-        (
-            "pip",
-            "pip Cache",
-            vec![
+        },
+        CacheDefinition {
+            id: "pip".to_string(),
+            name: "pip Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![
                 PathBuf::from(format!("{}/.cache/pip", home)),
                 PathBuf::from(format!("{}/pip/Cache", local_app_data)),
             ],
-        ),
-        // conda
-        (
-            "conda",
-            "Conda Cache",
-            vec![
+        },
+        CacheDefinition {
+            id: "conda".to_string(),
+            name: "Conda Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![
                 PathBuf::from(format!("{}/.conda/pkgs", home)),
                 PathBuf::from(format!("{}/conda/conda/pkgs", app_data)),
             ],
-        ),
-        // cargo
-        (
-            "cargo",
-            "Cargo Cache",
-            vec![PathBuf::from(format!("{}/.cargo/registry/cache", home))],
-        ),
-        // composer
-        (
-            "composer",
-            "Composer Cache",
-            vec![
+        },
+        CacheDefinition {
+            id: "cargo".to_string(),
+            name: "Cargo Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![
+                env_path_or("CARGO_HOME", PathBuf::from(format!("{}/.cargo", home)))
+                    .join("registry/cache"),
+            ],
+        },
+        CacheDefinition {
+            id: "composer".to_string(),
+            name: "Composer Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![
                 PathBuf::from(format!("{}/.composer/cache", home)),
                 PathBuf::from(format!("{}/Composer/cache", local_app_data)),
             ],
-        ),
-        // maven
-        (
-            "maven",
-            "Maven Cache",
-            vec![PathBuf::from(format!("{}/.m2/repository", home))],
-        ),
-        // gradle
-        (
-            "gradle",
-            "Gradle Cache",
-            vec![PathBuf::from(format!("{}/.gradle/caches", home))],
-        ),
-        // homebrew (macOS)
-        (
-            "homebrew",
-            "Homebrew Cache",
-            vec![PathBuf::from(format!("{}/Library/Caches/Homebrew", home))],
-        ),
-        // go modules
-        (
-            "go",
-            "Go Modules Cache",
-            vec![PathBuf::from(format!("{}/go/pkg/mod/cache", home))],
-        ),
-    ]
-}
-
-/// Scan all package manager caches
-pub fn scan_package_manager_caches() -> Vec<CacheInfo> {
-    let caches_config = get_package_manager_caches();
+        },
+        CacheDefinition {
+            id: "maven".to_string(),
+            name: "Maven Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![PathBuf::from(format!("{}/.m2/repository", home))],
+        },
+        CacheDefinition {
+            id: "gradle".to_string(),
+            name: "Gradle Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![PathBuf::from(format!("{}/.gradle/caches", home))],
+        },
+        CacheDefinition {
+            id: "homebrew".to_string(),
+            name: "Homebrew Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![PathBuf::from(format!("{}/Library/Caches/Homebrew", home))],
+        },
+        CacheDefinition {
+            id: "go".to_string(),
+            name: "Go Modules Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![
+                // GOPATH can be a platform-list-separator-joined list of
+                // workspaces; the module cache only lives under the first
+                // one, same as `go env GOPATH` treating it as the primary.
+                std::env::var("GOPATH")
+                    .ok()
+                    .and_then(|v| {
+                        v.split(if cfg!(windows) { ';' } else { ':' })
+                            .find(|segment| !segment.is_empty())
+                            .map(PathBuf::from)
+                    })
+                    .unwrap_or_else(|| PathBuf::from(format!("{}/go", home)))
+                    .join("pkg/mod/cache"),
+            ],
+        },
+        CacheDefinition {
+            id: "scoop".to_string(),
+            name: "Scoop Cache".to_string(),
+            cache_type: "package_manager".to_string(),
+            paths: vec![env_path_or(
+                "SCOOP_CACHE",
+                // Scoop's own default (when SCOOP_CACHE is unset) is
+                // %USERPROFILE%\scoop\cache - home-relative, not LOCALAPPDATA.
+                PathBuf::from(format!("{}/scoop/cache", home)),
+            )],
+        },
+    ];
+
+    CacheConfig { definitions }
+}
+
+/// Where a user's additional `[[cache]]` definitions live, merged on top of
+/// `default_cache_config` by `load_cache_config`.
+fn user_config_path() -> PathBuf {
+    home_dir().join(".dev-janitor").join("cache_config.toml")
+}
 
-    caches_config
+/// Load the effective cache config: the built-ins from `default_cache_config`,
+/// merged with whatever `[[cache]]` entries the user has added in
+/// `user_config_path()`. A user entry reusing a built-in `id` (e.g. to
+/// relocate `npm`'s cache) replaces that built-in rather than producing a
+/// second entry with the same id. A missing or entirely unparsable user file
+/// just falls back to the built-ins - same as the advisory/OSV caches
+/// degrading gracefully when their source isn't reachable. Entries are
+/// parsed one at a time rather than as a single `CacheConfig` document, so
+/// one malformed `[[cache]]` block (a typo'd key) doesn't throw out every
+/// other entry in the file along with it.
+pub fn load_cache_config() -> CacheConfig {
+    let mut config = default_cache_config();
+
+    if let Ok(content) = fs::read_to_string(user_config_path()) {
+        if let Ok(toml::Value::Table(root)) = content.parse::<toml::Value>() {
+            if let Some(entries) = root.get("cache").and_then(|v| v.as_array()) {
+                for entry in entries {
+                    let Ok(user_def) = CacheDefinition::deserialize(entry.clone()) else {
+                        continue;
+                    };
+                    if let Some(existing) =
+                        config.definitions.iter_mut().find(|def| def.id == user_def.id)
+                    {
+                        *existing = user_def;
+                    } else {
+                        config.definitions.push(user_def);
+                    }
+                }
+            }
+        }
+    }
+
+    config
+}
+
+/// Scan every cache definition in `config`, returning one `CacheInfo` per
+/// definition whose first existing path is non-empty. Both the built-in
+/// defaults and user-added definitions flow through this single code path.
+pub fn scan_with_config(config: &CacheConfig) -> Vec<CacheInfo> {
+    let mut caches: Vec<CacheInfo> = config
+        .definitions
         .par_iter()
-        .filter_map(|(id, name, paths)| {
-            // Find first existing path
-            for path in paths {
+        .filter_map(|def| {
+            for path in &def.paths {
                 if path.exists() {
-                    let size = get_dir_size(path);
+                    let (size, last_used) = scan_dir(path);
                     if size > 0 {
                         return Some(CacheInfo {
-                            id: id.to_string(),
-                            name: name.to_string(),
+                            id: def.id.clone(),
+                            name: def.name.clone(),
                             path: path.to_string_lossy().to_string(),
                             size,
                             size_display: format_size(size),
-                            cache_type: "package_manager".to_string(),
+                            cache_type: def.cache_type.clone(),
+                            last_used,
                         });
                     }
                 }
             }
             None
         })
-        .collect()
+        .collect();
+
+    caches.sort_by_key(|c| c.last_used);
+    caches
+}
+
+/// Scan all package manager caches, sorted by staleness (oldest `last_used`
+/// first) so a "clean what's stale" UI can walk the list top-down.
+pub fn scan_package_manager_caches() -> Vec<CacheInfo> {
+    scan_with_config(&load_cache_config())
 }
 
 /// Project cache patterns to look for
@@ -188,40 +412,300 @@ const PROJECT_CACHE_PATTERNS: &[(&str, &str)] = &[
     ("vendor", "Vendor Directory"),
 ];
 
-/// Scan a directory for project caches
+/// Options controlling what `scan_project_caches` is allowed to walk into.
+#[derive(Debug, Clone)]
+pub struct ProjectScanOptions {
+    /// Paths (and anything under them) the scan should never descend into,
+    /// regardless of `.gitignore`.
+    pub excluded_paths: Vec<PathBuf>,
+    /// Directory names to prune wherever they appear (e.g. a monorepo's own
+    /// vendored-but-not-gitignored bundle dir).
+    pub excluded_dir_names: Vec<String>,
+    /// Honor `.gitignore`/`.ignore`/global git excludes while walking - for
+    /// everything except the `PROJECT_CACHE_PATTERNS` directories themselves,
+    /// which are always traversed regardless (see
+    /// `scan_project_caches_cancellable`'s doc comment for why).
+    pub respect_gitignore: bool,
+}
+
+impl Default for ProjectScanOptions {
+    fn default() -> Self {
+        Self {
+            excluded_paths: Vec::new(),
+            excluded_dir_names: Vec::new(),
+            respect_gitignore: true,
+        }
+    }
+}
+
+/// Scan a directory for project caches. Walks once to collect matched
+/// directory paths (the same force-include/gitignore walk
+/// `scan_project_caches_cancellable` uses, via `collect_cache_dir_matches`),
+/// then sizes every match in parallel with rayon - the same
+/// discover-then-`par_iter` shape `scan_with_config` uses for package
+/// manager caches - so a workspace with several multi-gigabyte
+/// `node_modules`/`target` dirs sizes them concurrently instead of one at a
+/// time. `id`s are assigned from the matched list's index rather than
+/// `caches.len()` mid-loop, since that counter wouldn't be stable once
+/// sizing finishes out of order across threads.
+///
+/// This is the non-cancellable, non-progress-reporting cousin of
+/// `scan_project_caches_cancellable` - there's nothing to thread a single
+/// incrementing `ProgressCounters`/`stop` check through once sizing runs in
+/// parallel, so an interactive scan the user can abort partway through
+/// should still call that one instead.
 pub fn scan_project_caches(root_path: &str, max_depth: usize) -> Vec<CacheInfo> {
     let root = PathBuf::from(root_path);
     if !root.exists() {
         return Vec::new();
     }
 
+    let matched = collect_cache_dir_matches(&root, max_depth, &ProjectScanOptions::default());
+
+    let mut caches: Vec<CacheInfo> = matched
+        .par_iter()
+        .enumerate()
+        .filter_map(|(index, (pattern, name, path))| {
+            let (size, last_used) = scan_dir(path);
+            if size <= 1024 * 1024 {
+                // Only include if > 1MB
+                return None;
+            }
+            Some(CacheInfo {
+                id: format!("{}_{}", pattern, index),
+                name: name.to_string(),
+                path: path.to_string_lossy().to_string(),
+                size,
+                size_display: format_size(size),
+                cache_type: "project".to_string(),
+                last_used,
+            })
+        })
+        .collect();
+
+    caches.sort_by(|a, b| b.size.cmp(&a.size));
+    caches
+}
+
+/// The directory-matching half of `scan_project_caches` - find every
+/// `PROJECT_CACHE_PATTERNS` directory under `root` without sizing any of
+/// them. Pulled out so the sizing pass in `scan_project_caches` can run in
+/// parallel over a plain `Vec` instead of pushing into `CacheInfo`s mid-walk.
+/// Mirrors `scan_project_caches_cancellable`'s walker setup (force-included
+/// cache dirs, gitignore handling, explicit excludes) but is kept separate
+/// from it rather than shared, since that variant also needs to size and
+/// register each match immediately - before the walker would otherwise
+/// descend into it - to drive its `filter_entry` pruning and progress
+/// counters off a single pass.
+fn collect_cache_dir_matches(
+    root: &PathBuf,
+    max_depth: usize,
+    options: &ProjectScanOptions,
+) -> Vec<(&'static str, &'static str, PathBuf)> {
+    let matched_prefixes: Arc<Mutex<Vec<PathBuf>>> = Arc::new(Mutex::new(Vec::new()));
+    let filter_prefixes = Arc::clone(&matched_prefixes);
+    let excluded_paths = options.excluded_paths.clone();
+    let excluded_dir_names = options.excluded_dir_names.clone();
+
+    let mut cache_dir_overrides = ignore::overrides::OverrideBuilder::new(root);
+    for (pattern, _) in PROJECT_CACHE_PATTERNS {
+        let _ = cache_dir_overrides.add(pattern);
+    }
+    let cache_dir_overrides = cache_dir_overrides
+        .build()
+        .unwrap_or_else(|_| ignore::overrides::Override::empty());
+
+    let walker = WalkBuilder::new(root)
+        .max_depth(Some(max_depth))
+        .hidden(false)
+        .git_ignore(options.respect_gitignore)
+        .git_global(options.respect_gitignore)
+        .git_exclude(options.respect_gitignore)
+        .ignore(options.respect_gitignore)
+        .overrides(cache_dir_overrides)
+        .filter_entry(move |entry| {
+            let path = entry.path();
+            if excluded_paths.iter().any(|excluded| path.starts_with(excluded)) {
+                return false;
+            }
+            if let Some(name) = entry.file_name().to_str() {
+                if excluded_dir_names.iter().any(|excluded| excluded == name) {
+                    return false;
+                }
+            }
+            !filter_prefixes.lock().unwrap().iter().any(|prefix| path.starts_with(prefix))
+        })
+        .build();
+
+    let mut matches = Vec::new();
+    for result in walker {
+        let Ok(entry) = result else {
+            // Skip unreadable entries (permission-denied dirs, broken
+            // symlinks, ...) instead of aborting the whole scan.
+            continue;
+        };
+        if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+            continue;
+        }
+        let dir_name = entry.file_name().to_string_lossy();
+        let Some((pattern, name)) = PROJECT_CACHE_PATTERNS.iter().find(|(p, _)| dir_name == *p) else {
+            continue;
+        };
+        let path = entry.path().to_path_buf();
+        matches.push((*pattern, *name, path.clone()));
+        // Same reasoning as `scan_project_caches_cancellable`: registering
+        // the match before the walker reads this directory's children is
+        // what makes `filter_entry` prune them.
+        matched_prefixes.lock().unwrap().push(path);
+    }
+
+    matches
+}
+
+/// Cancellable, progress-reporting sibling of `scan_project_caches` - see
+/// `get_dir_size_cancellable`. A project tree can hold a `node_modules` or
+/// `target` with millions of files, so this is the variant a long-running
+/// scan command should actually call.
+///
+/// Walks with the `ignore` crate rather than raw `WalkDir` so `.gitignore`/
+/// `.ignore` rules (and `options`' explicit excludes) prune whole directories
+/// before they're ever read, instead of just being filtered out of the
+/// results afterward. `node_modules`/`target`/`dist`/etc. are near-universally
+/// gitignored themselves, which is exactly what this scanner exists to find -
+/// so those `PROJECT_CACHE_PATTERNS` names are force-included via an
+/// `ignore::overrides::Override`, and gitignore pruning only applies to
+/// everything else in the tree. Dotfile hiding is also turned off, since
+/// several patterns (`.venv`, `.next`, `.nuxt`, `.turbo`, `.gradle`) are
+/// themselves dot-prefixed.
+pub fn scan_project_caches_cancellable(
+    root_path: &str,
+    max_depth: usize,
+    options: &ProjectScanOptions,
+    stop: &AtomicBool,
+    progress: Option<&Sender<ScanProgress>>,
+) -> Vec<CacheInfo> {
+    let root = PathBuf::from(root_path);
+    if !root.exists() {
+        return Vec::new();
+    }
+
     let mut caches = Vec::new();
+    // Shared across the outer walk and every nested `scan_dir_cancellable`
+    // call below, so the emitted counts climb monotonically across the whole
+    // scan instead of resetting each time the walk descends into a matched
+    // cache directory.
+    let mut counters = ProgressCounters::default();
+
+    // Directories already matched and sized via `scan_dir_cancellable` below -
+    // there's no point letting the outer walk descend into one looking for a
+    // nested cache (a `dist` inside a `node_modules`), so `filter_entry`
+    // prunes anything under a path already in this list. `filter_entry`'s
+    // bound is `Fn(..) + Send + Sync + 'static` even for the serial `Walk`,
+    // so this needs `Arc<Mutex<_>>` rather than the cheaper `Rc<RefCell<_>>`.
+    let matched_prefixes: Arc<Mutex<Vec<PathBuf>>> = Arc::new(Mutex::new(Vec::new()));
+    let filter_prefixes = Arc::clone(&matched_prefixes);
+    let excluded_paths = options.excluded_paths.clone();
+    let excluded_dir_names = options.excluded_dir_names.clone();
 
-    for entry in WalkDir::new(&root)
-        .max_depth(max_depth)
-        .into_iter()
-        .filter_map(|e| e.ok())
-    {
-        if entry.file_type().is_dir() {
+    // Force-include the cache directory names themselves even when
+    // `.gitignore` lists them (the overwhelmingly common case for
+    // `node_modules`/`target`/`dist`/...). In `Override` glob semantics a
+    // bare pattern whitelists/force-includes a match; it's ordinary
+    // `.gitignore` syntax (a leading `!`) that excludes.
+    //
+    // Deliberately no catch-all `*` glob here: once an `Override` holds any
+    // whitelist glob, a non-directory path that doesn't match one is treated
+    // as ignored, so plain files stop showing up as their own walk entries
+    // (they're still sized correctly - that happens via `scan_dir_cancellable`
+    // on the matched directory, not by reading files from this walker). A
+    // catch-all would undo that trade the other way: it'd make every path
+    // match the override, and overrides take precedence over gitignore, so
+    // `.gitignore`-excluded directories unrelated to caching (a `secrets/`,
+    // a `dist/` full of build output you don't want walked) would get pulled
+    // back in right along with `node_modules`. Losing fine-grained progress
+    // ticks - and, in a large flat directory with no subdirectories, slightly
+    // slower `stop`-flag reaction, since both only fire on a yielded entry -
+    // is the smaller cost.
+    let mut cache_dir_overrides = ignore::overrides::OverrideBuilder::new(&root);
+    for (pattern, _) in PROJECT_CACHE_PATTERNS {
+        let _ = cache_dir_overrides.add(pattern);
+    }
+    let cache_dir_overrides = cache_dir_overrides.build().unwrap_or_else(|_| ignore::overrides::Override::empty());
+
+    let walker = WalkBuilder::new(&root)
+        .max_depth(Some(max_depth))
+        .hidden(false)
+        .git_ignore(options.respect_gitignore)
+        .git_global(options.respect_gitignore)
+        .git_exclude(options.respect_gitignore)
+        .ignore(options.respect_gitignore)
+        .overrides(cache_dir_overrides)
+        .filter_entry(move |entry| {
+            let path = entry.path();
+            if excluded_paths.iter().any(|excluded| path.starts_with(excluded)) {
+                return false;
+            }
+            if let Some(name) = entry.file_name().to_str() {
+                if excluded_dir_names.iter().any(|excluded| excluded == name) {
+                    return false;
+                }
+            }
+            !filter_prefixes.lock().unwrap().iter().any(|prefix| path.starts_with(prefix))
+        })
+        .build();
+
+    for result in walker {
+        if stop.load(Ordering::Relaxed) {
+            break;
+        }
+        let Ok(entry) = result else {
+            // Skip unreadable entries (permission-denied dirs, broken
+            // symlinks, ...) instead of aborting the whole scan.
+            continue;
+        };
+
+        let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+        if is_dir {
             let dir_name = entry.file_name().to_string_lossy();
+            let matched_pattern = PROJECT_CACHE_PATTERNS.iter().find(|(pattern, _)| dir_name == *pattern);
 
-            for (pattern, name) in PROJECT_CACHE_PATTERNS {
-                if dir_name == *pattern {
-                    let path = entry.path().to_path_buf();
-                    let size = get_dir_size(&path);
+            if let Some((pattern, name)) = matched_pattern {
+                let path = entry.path().to_path_buf();
+                // This also counts the directory's own entry into `counters`,
+                // so skip the outer `entries_seen` bump below for it.
+                let (size, last_used) = scan_dir_cancellable(&path, stop, progress, &mut counters);
 
-                    if size > 1024 * 1024 {
-                        // Only include if > 1MB
-                        caches.push(CacheInfo {
-                            id: format!("{}_{}", pattern, caches.len()),
-                            name: name.to_string(),
-                            path: path.to_string_lossy().to_string(),
-                            size,
-                            size_display: format_size(size),
-                            cache_type: "project".to_string(),
-                        });
-                    }
+                if size > 1024 * 1024 {
+                    // Only include if > 1MB
+                    caches.push(CacheInfo {
+                        id: format!("{}_{}", pattern, caches.len()),
+                        name: name.to_string(),
+                        path: path.to_string_lossy().to_string(),
+                        size,
+                        size_display: format_size(size),
+                        cache_type: "project".to_string(),
+                        last_used,
+                    });
                 }
+
+                // Registering the match here, before the walker reads this
+                // directory's children, is what makes `filter_entry` above
+                // prune them - `scan_dir_cancellable` just walked this whole
+                // subtree itself, so descending into it again would double
+                // every entry underneath.
+                matched_prefixes.lock().unwrap().push(path);
+                continue;
+            }
+        }
+
+        counters.entries_seen += 1;
+        if counters.entries_seen % PROGRESS_INTERVAL == 0 {
+            if let Some(sender) = progress {
+                let _ = sender.try_send(ScanProgress {
+                    entries_seen: counters.entries_seen,
+                    bytes_counted: counters.bytes_counted,
+                    current_path: entry.path().display().to_string(),
+                });
             }
         }
     }
@@ -231,23 +715,196 @@ pub fn scan_project_caches(root_path: &str, max_depth: usize) -> Vec<CacheInfo>
     caches
 }
 
+/// How to order candidates within a `CacheDeleteScope::Group` before taking
+/// (or excluding) a slice from the front of that order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CacheSort {
+    /// Biggest `size` first.
+    Largest,
+    /// Stalest `last_used` first.
+    Oldest,
+    /// A-Z by `name`.
+    Alpha,
+}
+
+/// Which caches a bulk action should act on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CacheDeleteScope {
+    /// Every entry passed in.
+    All,
+    /// Sort by `sort`, then take the first `n` entries - or, with `invert`,
+    /// everything *except* the last `n` in that order. E.g. "the 5 largest"
+    /// is `Group { sort: Largest, invert: false, n: 5 }`; "everything except
+    /// the 3 newest" is `Group { sort: Oldest, invert: true, n: 3 }` (sort
+    /// oldest-first, then keep all but the 3 at the newest end).
+    Group { sort: CacheSort, invert: bool, n: usize },
+}
+
+/// Select the subset of `entries` a `CacheDeleteScope` names, for a bulk
+/// action like "clean the 5 largest caches" rather than one at a time.
+pub fn select_caches(mut entries: Vec<CacheInfo>, scope: CacheDeleteScope) -> Vec<CacheInfo> {
+    let CacheDeleteScope::Group { sort, invert, n } = scope else {
+        return entries;
+    };
+
+    match sort {
+        CacheSort::Largest => entries.sort_by(|a, b| b.size.cmp(&a.size)),
+        CacheSort::Oldest => entries.sort_by_key(|c| c.last_used),
+        CacheSort::Alpha => entries.sort_by(|a, b| a.name.cmp(&b.name)),
+    }
+
+    if invert {
+        let keep = entries.len().saturating_sub(n);
+        entries.truncate(keep);
+    } else {
+        entries.truncate(n);
+    }
+    entries
+}
+
+/// Delete every cache in `selection`, reusing `clean_cache` per entry (in
+/// parallel - each deletion is independent I/O, same as the scans in this
+/// file). `CleanManyResult.results` holds exactly one `Result` per input
+/// entry, in the same order as `selection`, so a caller zipping it back
+/// against `selection` by index can rely on matching lengths; the
+/// aggregate freed/succeeded/total numbers live alongside it as their own
+/// fields rather than as an extra element smuggled into `results`. Freed
+/// bytes are measured fresh right before each deletion rather than
+/// trusting `entry.size` from whenever the cache was scanned, since a
+/// cache's contents can have changed in between.
+pub fn clean_many(selection: &[CacheInfo]) -> CleanManyResult {
+    let outcomes: Vec<Result<(u64, String), String>> = selection
+        .par_iter()
+        .map(|entry| remove_cache_dir(&entry.path))
+        .collect();
+
+    let succeeded = outcomes.iter().filter(|result| result.is_ok()).count();
+    let freed: u64 = outcomes
+        .iter()
+        .filter_map(|result| result.as_ref().ok())
+        .map(|(size, _)| *size)
+        .sum();
+
+    let results: Vec<Result<String, String>> =
+        outcomes.into_iter().map(|result| result.map(|(_, message)| message)).collect();
+
+    CleanManyResult {
+        results,
+        freed,
+        succeeded,
+        total: selection.len(),
+    }
+}
+
+/// Outcome of `clean_many`: one `Result` per input entry, in the same order
+/// and length as `selection`, plus the aggregate numbers a caller would
+/// otherwise have to re-derive by summing over it. Kept separate from
+/// `results` rather than appended as an extra element - a `Vec` one longer
+/// than `selection` is exactly the kind of thing a caller that zips or
+/// indexes against the input selection would misalign past the last real
+/// entry on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CleanManyResult {
+    pub results: Vec<Result<String, String>>,
+    pub freed: u64,
+    pub succeeded: usize,
+    pub total: usize,
+}
+
 /// Clean a cache directory
 pub fn clean_cache(path: &str) -> Result<String, String> {
+    remove_cache_dir(path).map(|(_, message)| message)
+}
+
+/// Refuse to run `fs::remove_dir_all` on the home directory, a filesystem
+/// root, or any shallow ancestor of it (`/home`, `/Users`, `C:\Users`, ...).
+/// `CacheInfo.path` can now originate from a user-edited `cache_config.toml`
+/// (see `load_cache_config`) rather than only from our own hardcoded/scanned
+/// lists, so a typo'd or malicious entry must not be able to turn "clean
+/// cache" into "delete everything". Compares canonicalized paths so a
+/// trailing slash or `..` segment can't slip past the check. A real cache is
+/// always several levels below a home directory (`~/.cache/...`,
+/// `~/go/pkg/mod/cache`, ...), so rather than hardcode every OS's home
+/// parent convention, anything shallower than `MIN_SAFE_DEPTH` components is
+/// rejected outright alongside the exact-home-directory match.
+const MIN_SAFE_DEPTH: usize = 3;
+
+/// Is `path` itself a known home-directory-parent (`/home`, `/Users`,
+/// `C:\Users`), rather than just the current user's own home? The depth
+/// guard above only rejects paths shallower than `MIN_SAFE_DEPTH`, which
+/// stops `/home` or `/Users` directly but lets a *sibling* home directory
+/// at the same depth as the current user's - `/home/otheruser`,
+/// `C:\Users\otheruser` - through, since it's neither shallow nor an exact
+/// match for `home_dir()`. Checking the parent against this list closes
+/// that gap regardless of which user's home a typo'd or malicious
+/// `cache_config.toml` entry names.
+fn is_home_parent_dir(path: &std::path::Path) -> bool {
+    let mut components = path
+        .components()
+        .filter(|c| !matches!(c, std::path::Component::Prefix(_)));
+    matches!(components.next(), Some(std::path::Component::RootDir))
+        && matches!(
+            components.next(),
+            Some(std::path::Component::Normal(name))
+                if name.to_str().is_some_and(|n| n.eq_ignore_ascii_case("home") || n.eq_ignore_ascii_case("users"))
+        )
+        && components.next().is_none()
+}
+
+fn is_protected_path(path: &std::path::Path) -> bool {
+    // A path that won't even canonicalize (permission error, broken symlink,
+    // stale mount) is treated as protected rather than "not protected" - this
+    // guard exists precisely to fail closed against an unexpected path, so an
+    // inability to resolve it at all is itself a reason not to proceed.
+    let Ok(canonical) = path.canonicalize() else {
+        return true;
+    };
+    // Exclude the Windows drive prefix (`C:`) from the depth count so
+    // `C:\Users` and `/Users` are judged the same way instead of the prefix
+    // making Windows paths look one component deeper than their Unix
+    // equivalent.
+    let depth = canonical
+        .components()
+        .filter(|c| !matches!(c, std::path::Component::Prefix(_)))
+        .count();
+    if depth < MIN_SAFE_DEPTH {
+        return true;
+    }
+    if home_dir().canonicalize().map(|h| h == canonical).unwrap_or(false) {
+        return true;
+    }
+    // Check both the canonicalized parent and the original, pre-canonicalize
+    // one: on a system where `/home` is itself a symlink or bind mount (e.g.
+    // `/home -> /var/home`, as on Fedora Silverblue-style distros),
+    // `canonical`'s parent no longer literally reads "home", but a
+    // `cache_config.toml` entry written as `/home/otheruser` is still
+    // exactly the sibling-home path this check exists to catch.
+    canonical.parent().is_some_and(is_home_parent_dir)
+        || path.parent().is_some_and(is_home_parent_dir)
+}
+
+/// Delete `path`, returning the bytes freed alongside the message
+/// `clean_cache` formats. Split out so `clean_many` can get the freed size
+/// back without walking the directory a second time just to re-derive it.
+fn remove_cache_dir(path: &str) -> Result<(u64, String), String> {
     let cache_path = PathBuf::from(path);
 
     if !cache_path.exists() {
         return Err(format!("Path does not exist: {}", path));
     }
 
+    if is_protected_path(&cache_path) {
+        return Err(format!("Refusing to delete protected path: {}", path));
+    }
+
     // Get size before deletion
     let size_before = get_dir_size(&cache_path);
 
     // Try to remove the directory
     match fs::remove_dir_all(&cache_path) {
-        Ok(_) => Ok(format!(
-            "Successfully cleaned {} (freed {})",
-            path,
-            format_size(size_before)
+        Ok(_) => Ok((
+            size_before,
+            format!("Successfully cleaned {} (freed {})", path, format_size(size_before)),
         )),
         Err(e) => {
             // Try with more aggressive approach on Windows
@@ -257,10 +914,9 @@ pub fn clean_cache(path: &str) -> Result<String, String> {
                 if remove_readonly_and_delete(&cache_path).is_err() {
                     return Err(format!("Failed to clean {}: {}", path, e));
                 }
-                Ok(format!(
-                    "Successfully cleaned {} (freed {})",
-                    path,
-                    format_size(size_before)
+                Ok((
+                    size_before,
+                    format!("Successfully cleaned {} (freed {})", path, format_size(size_before)),
                 ))
             }
 
@@ -270,6 +926,82 @@ pub fn clean_cache(path: &str) -> Result<String, String> {
     }
 }
 
+/// Outcome of evaluating one `CacheInfo` against `prune_caches`' staleness
+/// threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PruneResult {
+    pub id: String,
+    pub path: String,
+    pub freed: u64,
+    pub freed_display: String,
+    pub status: String, // "pruned", "previewed", "skipped", or "error"
+}
+
+/// Garbage-collect stale caches: delete (or, with `dry_run`, just report)
+/// every entry whose `last_used` is older than `older_than_days`, leaving
+/// recently-touched caches alone. Unlike `clean_cache`'s all-or-nothing
+/// delete, this lets a "free up space" action skip package-manager caches
+/// that are still in active use.
+pub fn prune_caches(entries: &[CacheInfo], older_than_days: u64, dry_run: bool) -> Vec<PruneResult> {
+    let threshold_secs = older_than_days.saturating_mul(24 * 60 * 60);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    entries
+        .iter()
+        .map(|entry| {
+            if now.saturating_sub(entry.last_used) < threshold_secs {
+                return PruneResult {
+                    id: entry.id.clone(),
+                    path: entry.path.clone(),
+                    freed: 0,
+                    freed_display: format_size(0),
+                    status: "skipped".to_string(),
+                };
+            }
+
+            if dry_run {
+                return PruneResult {
+                    id: entry.id.clone(),
+                    path: entry.path.clone(),
+                    freed: entry.size,
+                    freed_display: entry.size_display.clone(),
+                    status: "previewed".to_string(),
+                };
+            }
+
+            if is_protected_path(std::path::Path::new(&entry.path)) {
+                return PruneResult {
+                    id: entry.id.clone(),
+                    path: entry.path.clone(),
+                    freed: 0,
+                    freed_display: format_size(0),
+                    status: "error".to_string(),
+                };
+            }
+
+            match fs::remove_dir_all(&entry.path) {
+                Ok(_) => PruneResult {
+                    id: entry.id.clone(),
+                    path: entry.path.clone(),
+                    freed: entry.size,
+                    freed_display: entry.size_display.clone(),
+                    status: "pruned".to_string(),
+                },
+                Err(_) => PruneResult {
+                    id: entry.id.clone(),
+                    path: entry.path.clone(),
+                    freed: 0,
+                    freed_display: format_size(0),
+                    status: "error".to_string(),
+                },
+            }
+        })
+        .collect()
+}
+
 #[cfg(target_os = "windows")]
 fn remove_readonly_and_delete(path: &PathBuf) -> std::io::Result<()> {
     use std::os::windows::fs::MetadataExt;
@@ -0,0 +1,50 @@
+//! Tauri commands for the capability gate (see `crate::acl`)
+
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+use crate::acl::{elevate, list_granted, revoke_capability, Capability};
+
+/// Every capability the current frontend context holds, so the UI can
+/// reflect what it's allowed to do (e.g. disable a delete button) instead
+/// of discovering a command is refused only after the user clicks it.
+#[tauri::command]
+pub fn list_granted_capabilities_cmd() -> Vec<Capability> {
+    list_granted()
+}
+
+/// Grant `capability`, after showing the user a native OS confirmation
+/// dialog - rendered outside the webview, so a compromised webview can't
+/// script its way past it the way it could a plain IPC argument. Only
+/// calls `crate::acl::elevate` if the user actually confirms; declining (or
+/// dismissing) the dialog refuses the grant.
+#[tauri::command]
+pub fn grant_capability_cmd(app: tauri::AppHandle, capability: Capability) -> Result<(), String> {
+    let confirmed = app
+        .dialog()
+        .message(format!(
+            "Dev Janitor wants to enable `{:?}`, allowing it to perform irreversible actions \
+             (deleting files, killing processes, uninstalling tools). Only allow this if you \
+             just asked for it.",
+            capability
+        ))
+        .title("Confirm capability grant")
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            "Allow".to_string(),
+            "Deny".to_string(),
+        ))
+        .blocking_show();
+
+    if !confirmed {
+        return Err("Capability grant declined by user".to_string());
+    }
+
+    elevate(capability)
+}
+
+/// Revoke `capability`, e.g. once the user is done with a destructive
+/// workflow and wants the app back to its default, read-only posture.
+#[tauri::command]
+pub fn revoke_capability_cmd(capability: Capability) {
+    revoke_capability(capability)
+}
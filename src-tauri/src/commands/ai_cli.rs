@@ -0,0 +1,71 @@
+//! Tauri commands for managing AI CLI tools
+
+use crate::acl::{require_capability, Capability};
+use crate::ai_cli::{
+    get_ai_cli_tools, install_ai_tool_streaming, install_ai_tools, uninstall_ai_tool_streaming,
+    uninstall_ai_tools, update_ai_tool_streaming, update_ai_tools, AiCliTool, BatchResult,
+};
+use tauri::AppHandle;
+
+/// Get all supported AI CLI tools with their status
+#[tauri::command]
+pub fn get_ai_cli_tools_cmd() -> Vec<AiCliTool> {
+    get_ai_cli_tools()
+}
+
+/// Install a single AI CLI tool, streaming progress via `ai-tool-progress` events
+#[tauri::command]
+pub async fn install_ai_tool_cmd(app: AppHandle, tool_id: String) -> Result<String, String> {
+    install_ai_tool_streaming(&app, &tool_id).await
+}
+
+/// Update a single AI CLI tool, streaming progress via `ai-tool-progress` events
+#[tauri::command]
+pub async fn update_ai_tool_cmd(app: AppHandle, tool_id: String) -> Result<String, String> {
+    update_ai_tool_streaming(&app, &tool_id).await
+}
+
+/// Uninstall a single AI CLI tool, streaming progress via `ai-tool-progress` events
+#[tauri::command]
+pub async fn uninstall_ai_tool_cmd(app: AppHandle, tool_id: String) -> Result<String, String> {
+    require_capability(Capability::DestructiveDelete)?;
+    uninstall_ai_tool_streaming(&app, &tool_id).await
+}
+
+/// Install several AI CLI tools in one shot, or every installed tool when `all` is set
+#[tauri::command]
+pub fn install_ai_tools_cmd(tool_ids: Vec<String>, all: bool) -> Vec<BatchResult> {
+    install_ai_tools(tool_ids, all)
+}
+
+/// Update several AI CLI tools in one shot, or every installed tool when `all` is set
+#[tauri::command]
+pub fn update_ai_tools_cmd(tool_ids: Vec<String>, all: bool) -> Vec<BatchResult> {
+    update_ai_tools(tool_ids, all)
+}
+
+/// Uninstall several AI CLI tools in one shot, or every installed tool when `all` is set
+#[tauri::command]
+pub fn uninstall_ai_tools_cmd(tool_ids: Vec<String>, all: bool) -> Vec<BatchResult> {
+    if let Err(e) = require_capability(Capability::DestructiveDelete) {
+        // `tool_ids` is empty for the common `all: true` call (the real
+        // target list is only resolved inside `uninstall_ai_tools`), so
+        // mapping over it directly would silently return an empty batch
+        // instead of surfacing the refusal - report one result named for
+        // the refused request instead of per (unresolved) tool id.
+        let refused_ids = if tool_ids.is_empty() {
+            vec!["*".to_string()]
+        } else {
+            tool_ids
+        };
+        return refused_ids
+            .into_iter()
+            .map(|id| BatchResult {
+                tool_id: id,
+                status: "error".to_string(),
+                output: e.clone(),
+            })
+            .collect();
+    }
+    uninstall_ai_tools(tool_ids, all)
+}
@@ -0,0 +1,9 @@
+//! Tauri commands for environment diagnostics
+
+use crate::diagnostics::{get_diagnostics, DiagnosticsReport};
+
+/// Aggregate OS/runtime/AI-tool versions into a single "doctor" report
+#[tauri::command]
+pub fn get_diagnostics_cmd() -> DiagnosticsReport {
+    get_diagnostics()
+}
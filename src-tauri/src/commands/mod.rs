@@ -1,20 +1,24 @@
 //! Tauri command modules
 
+pub mod acl;
 pub mod ai_cleanup;
 pub mod ai_cli;
 pub mod cache;
 pub mod chat_history;
 pub mod config;
+pub mod diagnostics;
 pub mod packages;
 pub mod security;
 pub mod services;
 pub mod tools;
 
+pub use acl::*;
 pub use ai_cleanup::*;
 pub use ai_cli::*;
 pub use cache::*;
 pub use chat_history::*;
 pub use config::*;
+pub use diagnostics::*;
 pub use packages::*;
 pub use security::*;
 pub use services::*;
@@ -0,0 +1,78 @@
+//! Tauri commands for global package manager scanning and maintenance
+
+use tauri::{AppHandle, Emitter};
+
+use crate::acl::{require_capability, Capability};
+use crate::package_manager::outdated::{default_ttl_secs, get_outdated, merge_outdated, poll_latest_updates};
+use crate::package_manager::{discover_managers, OutdatedInfo, PackageInfo};
+
+const PACKAGES_OUTDATED_EVENT: &str = "packages-outdated-updated";
+
+/// List globally installed packages across every detected package manager
+#[tauri::command]
+pub fn scan_packages() -> Vec<PackageInfo> {
+    discover_managers()
+        .iter()
+        .flat_map(|m| m.list_packages())
+        .collect()
+}
+
+/// Update a single package on the given manager. Refuses a major-version bump
+/// unless `allow_major` is set - see `PackageManager::update_package`.
+#[tauri::command]
+pub fn update_package(manager: String, name: String, allow_major: bool) -> Result<String, String> {
+    let managers = discover_managers();
+    let mgr = managers
+        .iter()
+        .find(|m| m.name() == manager)
+        .ok_or_else(|| format!("Unknown package manager: {}", manager))?;
+    mgr.update_package(&name, allow_major)
+}
+
+/// Uninstall a single package on the given manager
+#[tauri::command]
+pub fn uninstall_package(manager: String, name: String) -> Result<String, String> {
+    require_capability(Capability::DestructiveDelete)?;
+    let managers = discover_managers();
+    let mgr = managers
+        .iter()
+        .find(|m| m.name() == manager)
+        .ok_or_else(|| format!("Unknown package manager: {}", manager))?;
+    mgr.uninstall_package(&name)
+}
+
+/// Kick off a background outdated-package check for one manager, reusing the
+/// on-disk cache when it's still within the TTL. Emits `packages-outdated-updated`
+/// with the merged results once the check completes so the UI can update the
+/// table without blocking the initial `scan_packages` load.
+#[tauri::command]
+pub async fn check_outdated_packages_cmd(
+    app: AppHandle,
+    manager: String,
+) -> Result<Vec<PackageInfo>, String> {
+    let managers = discover_managers();
+    let mgr = managers
+        .into_iter()
+        .find(|m| m.name() == manager)
+        .ok_or_else(|| format!("Unknown package manager: {}", manager))?;
+
+    let mut packages = mgr.list_packages();
+    let outdated: std::collections::HashMap<String, OutdatedInfo> =
+        tauri::async_runtime::spawn_blocking(move || get_outdated(mgr.as_ref(), default_ttl_secs()))
+            .await
+            .map_err(|e| format!("Outdated check task failed: {}", e))?;
+
+    merge_outdated(&mut packages, &outdated);
+    let _ = app.emit(PACKAGES_OUTDATED_EVENT, &packages);
+
+    Ok(packages)
+}
+
+/// Pull in whatever the background refresh sweep (started at app launch) has
+/// already resolved for `manager`, without starting a new network check.
+/// Cheap enough for the UI to call on a polling interval.
+#[tauri::command]
+pub fn poll_latest_updates_cmd(manager: String, mut packages: Vec<PackageInfo>) -> Vec<PackageInfo> {
+    poll_latest_updates(&manager, &mut packages);
+    packages
+}
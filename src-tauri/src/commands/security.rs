@@ -1,13 +1,32 @@
 //! Tauri commands for AI tool security scanning
 
+use crate::acl::{require_capability, Capability};
 use crate::security_scan::{
-    scan_ai_tool_security, scan_specific_tool, get_rules, SecurityScanResult,
+    apply_all, apply_remediation, approve_config_file, render, scan_ai_tool_security,
+    scan_specific_tool, scan_supply_chain, get_rules, AppliedFix, OutputFormat, SecurityFinding,
+    SecurityScanResult,
 };
 
-/// Perform a full security scan of all AI tools
+/// Perform a full security scan of all AI tools. The port checks now make
+/// real network connections (see `check_exposed_ports`'s off-box
+/// reachability probe), so like `scan_supply_chain_cmd` this runs on a
+/// worker task rather than blocking the command handler.
 #[tauri::command]
-pub fn scan_security_cmd() -> SecurityScanResult {
-    scan_ai_tool_security()
+pub async fn scan_security_cmd() -> Result<SecurityScanResult, String> {
+    tauri::async_runtime::spawn_blocking(scan_ai_tool_security)
+        .await
+        .map_err(|e| format!("Security scan task failed: {}", e))
+}
+
+/// Cross-reference installed cargo/pip packages against their advisory
+/// databases (RustSec, OSV.dev). Network-bound on a cache miss, so this runs
+/// on a worker task rather than the main scan path (see `scan_packages` /
+/// `check_outdated_packages_cmd` for the same split).
+#[tauri::command]
+pub async fn scan_supply_chain_cmd() -> Result<Vec<SecurityFinding>, String> {
+    tauri::async_runtime::spawn_blocking(scan_supply_chain)
+        .await
+        .map_err(|e| format!("Supply-chain scan task failed: {}", e))
 }
 
 /// Get list of supported tools for scanning  
@@ -26,10 +45,69 @@ pub fn get_security_tools_cmd() -> Vec<SecurityToolInfo> {
         .collect()
 }
 
-/// Scan a specific tool only
+/// Scan a specific tool only. Off the main command path for the same reason
+/// as `scan_security_cmd`.
+#[tauri::command]
+pub async fn scan_tool_security_cmd(tool_id: String) -> Result<Option<SecurityScanResult>, String> {
+    tauri::async_runtime::spawn_blocking(move || scan_specific_tool(&tool_id))
+        .await
+        .map_err(|e| format!("Security scan task failed: {}", e))
+}
+
+/// Apply (or, with `dry_run`, just preview) one finding's structured remediation.
+/// Previewing needs no capability - only `dry_run: false`, which actually
+/// rewrites a file, is gated behind `DestructiveDelete`.
+#[tauri::command]
+pub fn apply_remediation_cmd(finding: SecurityFinding, dry_run: bool) -> Result<AppliedFix, String> {
+    if !dry_run {
+        require_capability(Capability::DestructiveDelete)?;
+    }
+    apply_remediation(&finding, dry_run)
+}
+
+/// Apply (or preview) every given finding's remediation in one batch. Findings
+/// with no structured remediation, or whose target no longer matches, come
+/// back with `status: "error"` instead of failing the whole batch - same
+/// treatment for a missing `DestructiveDelete` capability on a non-dry-run.
+#[tauri::command]
+pub fn apply_all_remediations_cmd(findings: Vec<SecurityFinding>, dry_run: bool) -> Vec<AppliedFix> {
+    if !dry_run {
+        if let Err(e) = require_capability(Capability::DestructiveDelete) {
+            return findings
+                .iter()
+                .map(|f| AppliedFix {
+                    tool_id: f.tool_id.clone(),
+                    issue: f.issue.clone(),
+                    status: "error".to_string(),
+                    diff: e.clone(),
+                    backup_path: None,
+                })
+                .collect();
+        }
+    }
+    apply_all(&findings, dry_run)
+}
+
+/// Render a scan result in the given format, for exporting to SARIF-aware
+/// dashboards, CI annotations, or just a flatter summary feed. Pure
+/// in-memory formatting, so unlike the scan commands themselves this doesn't
+/// need a worker task.
+#[tauri::command]
+pub fn export_security_report_cmd(result: SecurityScanResult, format: OutputFormat) -> String {
+    render(&result, format)
+}
+
+/// Approve a `JsonTaskAudit`-flagged file's current content, recording its
+/// hash in the audit ledger so future scans stop re-flagging it unless its
+/// content changes again. Gated behind `DestructiveDelete` like every other
+/// "confirm this is fine, permanently" action in this module - without it a
+/// compromised webview could call this directly on the very malicious
+/// `tasks.json` that triggered the audit warning and silently whitelist it
+/// forever, defeating the audit ledger's whole purpose.
 #[tauri::command]
-pub fn scan_tool_security_cmd(tool_id: String) -> Option<SecurityScanResult> {
-    scan_specific_tool(&tool_id)
+pub fn approve_config_file_cmd(path: String) -> Result<(), String> {
+    require_capability(Capability::DestructiveDelete)?;
+    approve_config_file(&path)
 }
 
 /// Tool info for frontend display
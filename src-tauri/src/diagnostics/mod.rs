@@ -0,0 +1,76 @@
+//! Environment diagnostics module for Dev Janitor v2
+//! Aggregates OS/toolchain/AI-tool version information into one "doctor" report
+
+use serde::{Deserialize, Serialize};
+
+use crate::ai_cli::{find_config_files, get_ai_cli_tools, run_command_get_version, AiCliTool};
+
+/// Version info for a package manager or runtime Dev Janitor depends on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolVersion {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+/// Per-config-file health for an AI CLI tool, reusing `find_config_files`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolDiagnostics {
+    pub tool: AiCliTool,
+    pub configs_found: usize,
+    pub configs_total: usize,
+}
+
+/// A single structured environment report, the equivalent of `tauri-cli info`
+/// but for Dev Janitor's own dependencies
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticsReport {
+    pub os: String,
+    pub arch: String,
+    pub runtimes: Vec<ToolVersion>,
+    pub ai_tools: Vec<ToolDiagnostics>,
+}
+
+/// Versions of the package managers Dev Janitor shells out to
+fn get_runtime_versions() -> Vec<ToolVersion> {
+    let checks: &[(&str, &str, &[&str])] = &[
+        ("npm", "npm", &["--version"]),
+        ("Node.js", "node", &["--version"]),
+        ("pipx", "pipx", &["--version"]),
+        ("pip", "pip3", &["--version"]),
+        ("cargo", "cargo", &["--version"]),
+    ];
+
+    checks
+        .iter()
+        .map(|(name, cmd, args)| ToolVersion {
+            name: name.to_string(),
+            version: run_command_get_version(cmd, args),
+        })
+        .collect()
+}
+
+/// Installed state, version, and config-file health for every managed AI tool
+fn get_ai_tool_diagnostics() -> Vec<ToolDiagnostics> {
+    get_ai_cli_tools()
+        .into_iter()
+        .map(|tool| {
+            let configs = find_config_files(&tool.id);
+            ToolDiagnostics {
+                configs_total: configs.len(),
+                configs_found: configs.iter().filter(|c| c.exists).count(),
+                tool,
+            }
+        })
+        .collect()
+}
+
+/// Build a complete environment report: OS/arch, package-manager versions,
+/// and the installed/config state of every AI CLI tool Dev Janitor manages.
+pub fn get_diagnostics() -> DiagnosticsReport {
+    DiagnosticsReport {
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        runtimes: get_runtime_versions(),
+        ai_tools: get_ai_tool_diagnostics(),
+    }
+}
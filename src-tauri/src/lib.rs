@@ -4,6 +4,8 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
+mod acl;
+mod advisories;
 mod ai_cleanup;
 mod ai_cli;
 mod cache;
@@ -11,6 +13,7 @@ mod chat_history;
 mod commands;
 mod config;
 mod detection;
+mod diagnostics;
 mod error;
 mod package_manager;
 mod security_scan;
@@ -18,16 +21,22 @@ mod services;
 mod utils;
 
 use commands::{
-    analyze_path_cmd, clean_cache_cmd, clean_multiple_caches, delete_ai_junk_cmd,
-    delete_chat_file_cmd, delete_multiple_ai_junk, delete_multiple_chat_files,
-    delete_project_chat_history_cmd, diagnose_env_cmd, get_ai_cli_tools_cmd, get_all_processes_cmd,
-    get_common_dev_ports_cmd, get_dev_processes_cmd, get_path_suggestions_cmd, get_ports_cmd,
-    get_security_tools_cmd, get_shell_configs_cmd, get_tool_info, get_total_cache_size,
-    install_ai_tool_cmd, kill_process_cmd, scan_ai_junk_cmd, scan_caches, scan_chat_history_cmd,
+    analyze_path_cmd, apply_all_remediations_cmd, apply_remediation_cmd, approve_config_file_cmd,
+    check_outdated_packages_cmd, clean_cache_cmd, clean_multiple_caches,
+    delete_ai_junk_cmd, delete_chat_file_cmd, delete_multiple_ai_junk, delete_multiple_chat_files,
+    delete_project_chat_history_cmd, diagnose_env_cmd, export_security_report_cmd,
+    get_ai_cli_tools_cmd, get_all_processes_cmd,
+    get_common_dev_ports_cmd, get_dev_processes_cmd, get_diagnostics_cmd, get_path_suggestions_cmd,
+    get_ports_cmd, get_security_tools_cmd, get_shell_configs_cmd, get_tool_info,
+    get_total_cache_size, grant_capability_cmd, install_ai_tool_cmd, install_ai_tools_cmd,
+    kill_process_cmd, list_granted_capabilities_cmd, poll_latest_updates_cmd,
+    revoke_capability_cmd, scan_ai_junk_cmd, scan_caches, scan_chat_history_cmd,
     scan_global_chat_history_cmd, scan_packages, scan_project_caches_cmd, scan_security_cmd,
-    scan_tool_security_cmd, scan_tools, uninstall_ai_tool_cmd, uninstall_package, uninstall_tool,
-    update_ai_tool_cmd, update_package,
+    scan_supply_chain_cmd, scan_tool_security_cmd, scan_tools, uninstall_ai_tool_cmd,
+    uninstall_ai_tools_cmd, uninstall_package, uninstall_tool,
+    update_ai_tool_cmd, update_ai_tools_cmd, update_package,
 };
+use package_manager::outdated::spawn_background_refresh;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -36,6 +45,12 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_shell::init())
+        .setup(|_app| {
+            // Kick off the outdated/latest-version sweep in the background so
+            // it's already warm by the time the UI polls via poll_latest_updates_cmd
+            spawn_background_refresh(package_manager::discover_managers());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             // Tool commands
             scan_tools,
@@ -45,6 +60,8 @@ pub fn run() {
             scan_packages,
             update_package,
             uninstall_package,
+            check_outdated_packages_cmd,
+            poll_latest_updates_cmd,
             // Cache commands
             scan_caches,
             scan_project_caches_cmd,
@@ -77,10 +94,24 @@ pub fn run() {
             install_ai_tool_cmd,
             update_ai_tool_cmd,
             uninstall_ai_tool_cmd,
+            install_ai_tools_cmd,
+            update_ai_tools_cmd,
+            uninstall_ai_tools_cmd,
             // Security scan commands
             scan_security_cmd,
             scan_tool_security_cmd,
             get_security_tools_cmd,
+            scan_supply_chain_cmd,
+            apply_remediation_cmd,
+            apply_all_remediations_cmd,
+            export_security_report_cmd,
+            approve_config_file_cmd,
+            // Diagnostics commands
+            get_diagnostics_cmd,
+            // Capability/ACL commands
+            list_granted_capabilities_cmd,
+            grant_capability_cmd,
+            revoke_capability_cmd,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,93 @@
+//! Homebrew package manager support (macOS/Linux)
+
+use super::{PackageInfo, PackageManager};
+
+use crate::utils::command::command_no_window;
+
+pub struct BrewManager {
+    version: String,
+}
+
+impl BrewManager {
+    pub fn new() -> Option<Self> {
+        let output = run_brew_command(&["--version"])?;
+        // First line looks like "Homebrew 4.x.y"
+        let version = output
+            .lines()
+            .next()
+            .and_then(|l| l.split_whitespace().nth(1))
+            .unwrap_or("unknown")
+            .to_string();
+        Some(Self { version })
+    }
+}
+
+impl PackageManager for BrewManager {
+    fn name(&self) -> &str {
+        "brew"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn get_version(&self) -> Option<String> {
+        Some(self.version.clone())
+    }
+
+    fn list_packages(&self) -> Vec<PackageInfo> {
+        let mut packages = Vec::new();
+
+        let output = match run_brew_command(&["list", "--versions"]) {
+            Some(o) => o,
+            None => return packages,
+        };
+
+        for line in output.lines() {
+            let mut parts = line.split_whitespace();
+            let name = match parts.next() {
+                Some(n) => n.to_string(),
+                None => continue,
+            };
+            // `brew list --versions` prints every installed version; keep the newest
+            let version = parts.last().unwrap_or("unknown").to_string();
+
+            packages.push(PackageInfo {
+                name,
+                version,
+                latest: None,
+                manager: "brew".to_string(),
+                is_outdated: false,
+                description: None,
+            });
+        }
+
+        packages
+    }
+
+    fn update_package(&self, name: &str, _allow_major: bool) -> Result<String, String> {
+        // brew doesn't surface a pre-upgrade version comparison here, so there's
+        // nothing to classify against - `allow_major` is a no-op for this manager.
+        match run_brew_command(&["upgrade", name]) {
+            Some(output) => Ok(format!("Updated {} successfully:\n{}", name, output)),
+            None => Err(format!("Failed to update {}", name)),
+        }
+    }
+
+    fn uninstall_package(&self, name: &str) -> Result<String, String> {
+        match run_brew_command(&["uninstall", name]) {
+            Some(output) => Ok(format!("Uninstalled {} successfully:\n{}", name, output)),
+            None => Err(format!("Failed to uninstall {}", name)),
+        }
+    }
+}
+
+fn run_brew_command(args: &[&str]) -> Option<String> {
+    let output = command_no_window("brew").args(args).output().ok()?;
+
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        None
+    }
+}
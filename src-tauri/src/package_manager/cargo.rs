@@ -1,10 +1,41 @@
 //! Cargo package manager support
 
-use super::{PackageInfo, PackageManager};
+use super::outdated::{default_ttl_secs, fresh_entries, guard_major_update, is_newer, resolve_concurrently};
+use super::{OutdatedInfo, PackageInfo, PackageManager};
 use regex::Regex;
+use serde::Deserialize;
 
 use crate::utils::command::command_no_window;
 
+#[derive(Deserialize)]
+struct CratesIoResponse {
+    #[serde(rename = "crate")]
+    krate: CratesIoCrate,
+}
+
+#[derive(Deserialize)]
+struct CratesIoCrate {
+    max_stable_version: String,
+    description: Option<String>,
+}
+
+/// Look up the latest stable version and description for one crate.
+/// crates.io asks API clients to send an identifying User-Agent.
+fn fetch_crate_info(name: &str) -> Option<CratesIoCrate> {
+    let url = format!("https://crates.io/api/v1/crates/{}", name);
+    let response: CratesIoResponse = reqwest::blocking::Client::builder()
+        .user_agent("dev-janitor (https://github.com/cocojojo5213/Dev-Janitor)")
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .ok()?
+        .get(&url)
+        .send()
+        .ok()?
+        .json()
+        .ok()?;
+    Some(response.krate)
+}
+
 pub struct CargoManager {
     version: String,
 }
@@ -50,19 +81,25 @@ impl PackageManager for CargoManager {
         //     binary2
         let re = Regex::new(r"^(\S+)\s+v(\d+\.\d+\.\d+)").unwrap();
 
+        // Resolving latest versions/descriptions hits crates.io, so it never
+        // runs on this path. `check_outdated` runs on a worker task instead;
+        // here we only read whatever is already cached on disk from a prior run.
+        let outdated = fresh_entries(self.name(), default_ttl_secs());
+
         for line in output.lines() {
             if let Some(caps) = re.captures(line) {
                 let name = caps.get(1).map(|m| m.as_str()).unwrap_or("").to_string();
                 let version = caps.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
 
                 if !name.is_empty() {
+                    let cached = outdated.get(&name);
                     packages.push(PackageInfo {
+                        is_outdated: cached.map(|c| is_newer(&version, &c.latest)).unwrap_or(false),
+                        latest: cached.map(|c| c.latest.clone()),
+                        description: cached.and_then(|c| c.description.clone()),
+                        manager: "cargo".to_string(),
                         name,
                         version,
-                        latest: None, // Cargo doesn't easily provide latest version
-                        manager: "cargo".to_string(),
-                        is_outdated: false,
-                        description: None,
                     });
                 }
             }
@@ -71,7 +108,9 @@ impl PackageManager for CargoManager {
         packages
     }
 
-    fn update_package(&self, name: &str) -> Result<String, String> {
+    fn update_package(&self, name: &str, allow_major: bool) -> Result<String, String> {
+        guard_major_update(self, name, allow_major)?;
+
         match run_cargo_command(&["install", name, "--force"]) {
             Some(output) => Ok(format!("Updated {} successfully:\n{}", name, output)),
             None => Err(format!("Failed to update {}", name)),
@@ -84,6 +123,18 @@ impl PackageManager for CargoManager {
             None => Err(format!("Failed to uninstall {}", name)),
         }
     }
+
+    fn check_outdated(&self) -> std::collections::HashMap<String, OutdatedInfo> {
+        let packages = self.list_packages();
+        resolve_concurrently(&packages, |name| {
+            let info = fetch_crate_info(name)?;
+            Some(OutdatedInfo {
+                latest: info.max_stable_version,
+                description: info.description,
+                checked_at: 0, // stamped by `outdated::refresh_outdated`
+            })
+        })
+    }
 }
 
 fn run_cargo_command(args: &[&str]) -> Option<String> {
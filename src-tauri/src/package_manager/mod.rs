@@ -0,0 +1,105 @@
+//! Package manager abstraction for Dev Janitor v2
+//! Each concrete manager (npm, pip, cargo, ...) lists, updates, and uninstalls
+//! the globally installed packages for its ecosystem.
+
+pub mod brew;
+pub mod cargo;
+pub mod npm;
+pub mod outdated;
+pub mod pip;
+pub mod pipx;
+
+use serde::{Deserialize, Serialize};
+
+/// A globally installed package, as reported by one `PackageManager`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageInfo {
+    pub name: String,
+    pub version: String,
+    pub latest: Option<String>,
+    pub manager: String,
+    pub is_outdated: bool,
+    pub description: Option<String>,
+}
+
+/// Semver classification of an available update, from installed -> latest.
+/// `update_package` gates `Major` bumps behind `allow_major` so a bulk
+/// "update all" doesn't silently pull in a breaking release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpdateKind {
+    Patch,
+    Minor,
+    Major,
+    Prerelease,
+}
+
+/// The latest-version result of an outdated check for a single package
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutdatedInfo {
+    pub latest: String,
+    /// Package description/summary, when the registry queried by
+    /// `check_outdated` returns one alongside the version (crates.io, PyPI)
+    pub description: Option<String>,
+    pub checked_at: u64,
+}
+
+/// A global package manager Dev Janitor can inspect and manage
+///
+/// `Send + Sync` so a `Box<dyn PackageManager>` can be handed to the
+/// background refresh sweep in `outdated::spawn_background_refresh`.
+pub trait PackageManager: Send + Sync {
+    fn name(&self) -> &str;
+    fn is_available(&self) -> bool;
+    fn get_version(&self) -> Option<String>;
+    fn list_packages(&self) -> Vec<PackageInfo>;
+
+    /// Update a package. Implementations that can cheaply resolve the
+    /// installed/latest versions should refuse a `Major` bump unless
+    /// `allow_major` is set, returning an error naming the old -> new span
+    /// instead of silently installing a breaking release.
+    fn update_package(&self, name: &str, allow_major: bool) -> Result<String, String>;
+    fn uninstall_package(&self, name: &str) -> Result<String, String>;
+
+    /// Check the registry for newer versions of every installed package.
+    /// This is network-bound and slow, so callers should run it on a worker
+    /// task (see `outdated::refresh_outdated`) rather than the request path.
+    fn check_outdated(&self) -> std::collections::HashMap<String, OutdatedInfo> {
+        std::collections::HashMap::new()
+    }
+}
+
+/// Discover every package manager available on this machine, in a fixed
+/// detection order. `ai_cli` and the package commands use this instead of
+/// hardcoding a single manager so callers work across the whole set of
+/// globally installed developer tools.
+pub fn discover_managers() -> Vec<Box<dyn PackageManager>> {
+    let mut managers: Vec<Box<dyn PackageManager>> = Vec::new();
+
+    if let Some(m) = npm::NpmManager::new() {
+        managers.push(Box::new(m));
+    }
+    if let Some(m) = pip::PipManager::new() {
+        managers.push(Box::new(m));
+    }
+    if let Some(m) = cargo::CargoManager::new() {
+        managers.push(Box::new(m));
+    }
+    if let Some(m) = pipx::PipxManager::new() {
+        managers.push(Box::new(m));
+    }
+    #[cfg(not(target_os = "windows"))]
+    if let Some(m) = brew::BrewManager::new() {
+        managers.push(Box::new(m));
+    }
+
+    managers
+}
+
+/// Find the manager that owns a given package, trying each detected manager
+/// in turn. Used to route update/uninstall to the correct backend instead of
+/// hardcoding a command string per package.
+pub fn find_manager_for(name: &str) -> Option<Box<dyn PackageManager>> {
+    discover_managers()
+        .into_iter()
+        .find(|m| m.list_packages().iter().any(|p| p.name == name))
+}
@@ -1,6 +1,7 @@
 //! npm package manager support
 
-use super::{PackageInfo, PackageManager};
+use super::outdated::{default_ttl_secs, fresh_entries, guard_major_update};
+use super::{OutdatedInfo, PackageInfo, PackageManager};
 use serde::Deserialize;
 
 use crate::utils::command::command_no_window;
@@ -60,11 +61,10 @@ impl PackageManager for NpmManager {
             Err(_) => return packages,
         };
 
-        // Skip outdated check for now - it requires network and is slow
-        // TODO: Move to async background task
-        // let outdated_output = run_npm_command(&["outdated", "-g", "--json"]).unwrap_or_default();
-        let outdated: std::collections::HashMap<String, NpmOutdatedPackage> =
-            std::collections::HashMap::new();
+        // Outdated detection is network-bound and slow, so it never runs on
+        // this path. `check_outdated` runs on a worker task instead; here we
+        // only read whatever is already cached on disk from a prior run.
+        let outdated = fresh_entries(self.name(), default_ttl_secs());
 
         if let Some(deps) = list.dependencies {
             for (name, pkg) in deps {
@@ -93,7 +93,9 @@ impl PackageManager for NpmManager {
         packages
     }
 
-    fn update_package(&self, name: &str) -> Result<String, String> {
+    fn update_package(&self, name: &str, allow_major: bool) -> Result<String, String> {
+        guard_major_update(self, name, allow_major)?;
+
         match run_npm_command(&["update", "-g", name]) {
             Some(output) => Ok(format!("Updated {} successfully:\n{}", name, output)),
             None => Err(format!("Failed to update {}", name)),
@@ -106,6 +108,26 @@ impl PackageManager for NpmManager {
             None => Err(format!("Failed to uninstall {}", name)),
         }
     }
+
+    fn check_outdated(&self) -> std::collections::HashMap<String, OutdatedInfo> {
+        let output = run_npm_command(&["outdated", "-g", "--json"]).unwrap_or_default();
+        let outdated: std::collections::HashMap<String, NpmOutdatedPackage> =
+            serde_json::from_str(&output).unwrap_or_default();
+
+        outdated
+            .into_iter()
+            .map(|(name, pkg)| {
+                (
+                    name,
+                    OutdatedInfo {
+                        latest: pkg.latest,
+                        description: None,
+                        checked_at: 0, // stamped by `outdated::refresh_outdated`
+                    },
+                )
+            })
+            .collect()
+    }
 }
 
 fn run_npm_command(args: &[&str]) -> Option<String> {
@@ -0,0 +1,276 @@
+//! Cache-backed, background outdated-package detection
+//!
+//! Registry lookups (`npm outdated`, PyPI/crates.io queries, ...) are slow
+//! and network-bound, so `PackageManager::check_outdated` is never called on
+//! the request path. Instead it runs from a background sweep
+//! (`spawn_background_refresh`) or a spawned worker task, and this module
+//! persists the result to disk - plus an in-memory mirror - so repeated
+//! scans within the TTL window stay instant and offline-capable, and the UI
+//! can cheaply `poll_latest_updates` for whatever the sweep has resolved so far.
+
+use rayon::prelude::*;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::{OutdatedInfo, PackageManager, UpdateKind};
+
+/// Reuse outdated results within this window instead of hitting the network again
+const DEFAULT_TTL_SECS: u64 = 60 * 60;
+
+/// Upper bound on simultaneous registry connections a single `check_outdated`
+/// call may open, so a machine with hundreds of installed packages doesn't
+/// open hundreds of connections to crates.io/PyPI at once.
+pub const MAX_CONCURRENT_LOOKUPS: usize = 8;
+
+/// Process-wide mirror of the on-disk cache per manager, kept current by
+/// `refresh_outdated`. `poll_latest_updates` reads this directly so the UI
+/// can pull in newly-resolved versions without touching disk or the network.
+type LatestCache = Arc<RwLock<HashMap<String, HashMap<String, OutdatedInfo>>>>;
+
+fn latest_cache() -> &'static LatestCache {
+    static CACHE: OnceLock<LatestCache> = OnceLock::new();
+    CACHE.get_or_init(|| Arc::new(RwLock::new(HashMap::new())))
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct OutdatedCache {
+    /// When this cache was last refreshed, independent of whether `entries`
+    /// came back empty - distinguishes "never checked this manager" from
+    /// "checked recently and genuinely found zero outdated packages", which
+    /// an empty `entries` map alone can't (see `get_outdated`).
+    #[serde(default)]
+    checked_at: u64,
+    entries: HashMap<String, OutdatedInfo>,
+}
+
+fn cache_dir() -> PathBuf {
+    let home = env::var("HOME")
+        .or_else(|_| env::var("USERPROFILE"))
+        .unwrap_or_default();
+    PathBuf::from(home).join(".dev-janitor").join("cache")
+}
+
+fn cache_path(manager_name: &str) -> PathBuf {
+    cache_dir().join(format!("outdated_{}.json", manager_name))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn load_cache(manager_name: &str) -> OutdatedCache {
+    fs::read_to_string(cache_path(manager_name))
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_cache(manager_name: &str, cache: &OutdatedCache) {
+    let dir = cache_dir();
+    if fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+    if let Ok(json) = serde_json::to_string(cache) {
+        let _ = fs::write(cache_path(manager_name), json);
+    }
+}
+
+/// Entries from the on-disk cache that are still within the TTL window.
+/// Pure file read, no network access - safe to call from the request path.
+pub fn fresh_entries(manager_name: &str, ttl_secs: u64) -> HashMap<String, OutdatedInfo> {
+    let cache = load_cache(manager_name);
+    let cutoff = now_secs().saturating_sub(ttl_secs);
+    cache
+        .entries
+        .into_iter()
+        .filter(|(_, info)| info.checked_at >= cutoff)
+        .collect()
+}
+
+/// Run `check_outdated` on the given manager and persist the results to disk.
+/// Intended to be called from a spawned worker task, not the request path.
+pub fn refresh_outdated(manager: &dyn PackageManager) -> HashMap<String, OutdatedInfo> {
+    let checked_at = now_secs();
+    let fresh: HashMap<String, OutdatedInfo> = manager
+        .check_outdated()
+        .into_iter()
+        .map(|(name, mut info)| {
+            info.checked_at = checked_at;
+            (name, info)
+        })
+        .collect();
+
+    save_cache(
+        manager.name(),
+        &OutdatedCache { checked_at, entries: fresh.clone() },
+    );
+    mirror_in_memory(manager.name(), &fresh);
+    fresh
+}
+
+fn mirror_in_memory(manager_name: &str, entries: &HashMap<String, OutdatedInfo>) {
+    if let Ok(mut cache) = latest_cache().write() {
+        cache.insert(manager_name.to_string(), entries.clone());
+    }
+}
+
+/// Refresh every given manager's outdated cache on a background thread,
+/// intended to be kicked off once at app startup. Reuses the TTL cache via
+/// `get_outdated`, so a process restarted within the TTL window doesn't
+/// re-hit every registry - it just warms the in-memory mirror from disk.
+/// Managers run one after another (there are only ever a handful); bounded
+/// per-registry concurrency happens inside each manager's own
+/// `check_outdated`. `poll_latest_updates` is how the UI observes progress.
+pub fn spawn_background_refresh(managers: Vec<Box<dyn PackageManager>>) {
+    std::thread::spawn(move || {
+        for manager in &managers {
+            get_outdated(manager.as_ref(), default_ttl_secs());
+        }
+    });
+}
+
+/// Merge whatever the background refresh has already resolved for `manager_name`
+/// into `packages`, without touching the network or disk. Cheap enough to call
+/// on a UI polling interval.
+pub fn poll_latest_updates(manager_name: &str, packages: &mut [super::PackageInfo]) {
+    if let Ok(cache) = latest_cache().read() {
+        if let Some(outdated) = cache.get(manager_name) {
+            merge_outdated(packages, outdated);
+        }
+    }
+}
+
+/// Get outdated info for a manager, reusing the disk cache when it is still
+/// within `ttl_secs`, otherwise running a fresh (slow, network-bound) check.
+/// Gates on the cache's own `checked_at` marker rather than on whether its
+/// `entries` map is empty - an empty map is a legitimate result (every
+/// package happens to be up to date), and gating on emptiness would mean
+/// the TTL never actually suppresses the network refresh on a fully
+/// up-to-date machine.
+pub fn get_outdated(manager: &dyn PackageManager, ttl_secs: u64) -> HashMap<String, OutdatedInfo> {
+    let cache = load_cache(manager.name());
+    let cache_fresh = now_secs().saturating_sub(cache.checked_at) < ttl_secs;
+    if cache_fresh {
+        mirror_in_memory(manager.name(), &cache.entries);
+        return cache.entries;
+    }
+    refresh_outdated(manager)
+}
+
+/// True if `latest` is a newer release than `current`. Falls back to a plain
+/// string comparison when either side isn't valid semver (e.g. a package
+/// still on a pre-release or VCS-sourced version string).
+pub fn is_newer(current: &str, latest: &str) -> bool {
+    match (Version::parse(current), Version::parse(latest)) {
+        (Ok(c), Ok(l)) => l > c,
+        _ => current != latest,
+    }
+}
+
+/// Classify an installed -> latest jump as patch/minor/major/pre-release.
+/// `None` if either version isn't valid semver, or `latest` isn't actually newer.
+pub fn classify_update(current: &str, latest: &str) -> Option<UpdateKind> {
+    let current = Version::parse(current).ok()?;
+    let latest = Version::parse(latest).ok()?;
+    if latest <= current {
+        return None;
+    }
+    if !latest.pre.is_empty() {
+        Some(UpdateKind::Prerelease)
+    } else if latest.major != current.major {
+        Some(UpdateKind::Major)
+    } else if latest.minor != current.minor {
+        Some(UpdateKind::Minor)
+    } else {
+        Some(UpdateKind::Patch)
+    }
+}
+
+/// Refuse a `Major` version bump unless `allow_major` is set, using whatever
+/// the TTL cache already knows about `name` - shared by every manager whose
+/// `update_package` can cheaply resolve an installed/latest pair (cargo, npm,
+/// pip). Managers with no such pair (brew, pipx) have nothing to gate.
+pub fn guard_major_update(
+    manager: &dyn PackageManager,
+    name: &str,
+    allow_major: bool,
+) -> Result<(), String> {
+    if allow_major {
+        return Ok(());
+    }
+    let outdated = fresh_entries(manager.name(), default_ttl_secs());
+    let info = match outdated.get(name) {
+        Some(info) => info,
+        None => return Ok(()),
+    };
+    let installed = match manager
+        .list_packages()
+        .into_iter()
+        .find(|p| p.name == name)
+        .map(|p| p.version)
+    {
+        Some(installed) => installed,
+        None => return Ok(()),
+    };
+    if classify_update(&installed, &info.latest) == Some(UpdateKind::Major) {
+        return Err(format!(
+            "{} {} -> {} is a major version bump; pass allow_major to confirm",
+            name, installed, info.latest
+        ));
+    }
+    Ok(())
+}
+
+/// Merge freshly-resolved outdated info into an already-listed `PackageInfo` set
+pub fn merge_outdated(
+    packages: &mut [super::PackageInfo],
+    outdated: &HashMap<String, OutdatedInfo>,
+) {
+    for pkg in packages.iter_mut() {
+        if let Some(info) = outdated.get(&pkg.name) {
+            pkg.is_outdated = is_newer(&pkg.version, &info.latest);
+            pkg.latest = Some(info.latest.clone());
+            if info.description.is_some() {
+                pkg.description = info.description.clone();
+            }
+        }
+    }
+}
+
+/// Default TTL used when the caller doesn't have a strong opinion
+pub fn default_ttl_secs() -> u64 {
+    DEFAULT_TTL_SECS
+}
+
+/// Run `fetch` once per package with at most `MAX_CONCURRENT_LOOKUPS`
+/// requests in flight at a time - shared by every manager whose
+/// `check_outdated` hits a per-package registry endpoint (cargo, pip).
+/// Falls back to a plain sequential pass if the bounded thread pool can't be
+/// built (e.g. thread/fd exhaustion), so a constrained machine still gets a
+/// result instead of an empty one.
+pub fn resolve_concurrently<F>(
+    packages: &[super::PackageInfo],
+    fetch: F,
+) -> HashMap<String, OutdatedInfo>
+where
+    F: Fn(&str) -> Option<OutdatedInfo> + Sync,
+{
+    let run = |pkg: &super::PackageInfo| fetch(&pkg.name).map(|info| (pkg.name.clone(), info));
+
+    match rayon::ThreadPoolBuilder::new()
+        .num_threads(MAX_CONCURRENT_LOOKUPS)
+        .build()
+    {
+        Ok(pool) => pool.install(|| packages.par_iter().filter_map(run).collect()),
+        Err(_) => packages.iter().filter_map(run).collect(),
+    }
+}
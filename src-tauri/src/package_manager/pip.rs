@@ -1,6 +1,7 @@
 //! pip package manager support
 
-use super::{PackageInfo, PackageManager};
+use super::outdated::{default_ttl_secs, fresh_entries, guard_major_update, is_newer, resolve_concurrently};
+use super::{OutdatedInfo, PackageInfo, PackageManager};
 use serde::Deserialize;
 
 use crate::utils::command::command_no_window;
@@ -17,10 +18,29 @@ struct PipPackage {
 }
 
 #[derive(Deserialize)]
-struct PipOutdatedPackage {
-    name: String,
+struct PyPiResponse {
+    info: PyPiInfo,
+}
+
+#[derive(Deserialize)]
+struct PyPiInfo {
     version: String,
-    latest_version: String,
+    summary: Option<String>,
+}
+
+/// Look up the latest released version and summary for one package from PyPI
+fn fetch_pypi_info(name: &str) -> Option<PyPiInfo> {
+    let url = format!("https://pypi.org/pypi/{}/json", name);
+    let response: PyPiResponse = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .ok()?
+        .get(&url)
+        .send()
+        .ok()?
+        .json()
+        .ok()?;
+    Some(response.info)
 }
 
 impl PipManager {
@@ -71,16 +91,10 @@ impl PackageManager for PipManager {
             Err(_) => return packages,
         };
 
-        // Skip outdated check for now - it requires network and is very slow
-        // TODO: Move to async background task
-        // let outdated_output =
-        //     run_pip_command(&self.command, &["list", "--outdated", "--format=json"])
-        //         .unwrap_or_default();
-        // let outdated: Vec<PipOutdatedPackage> =
-        //     serde_json::from_str(&outdated_output).unwrap_or_default();
-
-        let outdated_map: std::collections::HashMap<String, String> =
-            std::collections::HashMap::new();
+        // Resolving latest versions/descriptions hits PyPI, so it never runs
+        // on this path. `check_outdated` runs on a worker task instead; here
+        // we only read whatever is already cached on disk from a prior run.
+        let outdated = fresh_entries(self.name(), default_ttl_secs());
 
         for pkg in list {
             // Skip common system packages
@@ -88,27 +102,25 @@ impl PackageManager for PipManager {
                 continue;
             }
 
-            let name_lower = pkg.name.to_lowercase();
-            let (is_outdated, latest) = if let Some(latest) = outdated_map.get(&name_lower) {
-                (true, Some(latest.clone()))
-            } else {
-                (false, None)
-            };
-
+            let cached = outdated.get(&pkg.name);
             packages.push(PackageInfo {
+                is_outdated: cached
+                    .map(|c| is_newer(&pkg.version, &c.latest))
+                    .unwrap_or(false),
+                latest: cached.map(|c| c.latest.clone()),
+                description: cached.and_then(|c| c.description.clone()),
+                manager: "pip".to_string(),
                 name: pkg.name,
                 version: pkg.version,
-                latest,
-                manager: "pip".to_string(),
-                is_outdated,
-                description: None,
             });
         }
 
         packages
     }
 
-    fn update_package(&self, name: &str) -> Result<String, String> {
+    fn update_package(&self, name: &str, allow_major: bool) -> Result<String, String> {
+        guard_major_update(self, name, allow_major)?;
+
         match run_pip_command(&self.command, &["install", "--upgrade", name]) {
             Some(output) => Ok(format!("Updated {} successfully:\n{}", name, output)),
             None => Err(format!("Failed to update {}", name)),
@@ -121,6 +133,18 @@ impl PackageManager for PipManager {
             None => Err(format!("Failed to uninstall {}", name)),
         }
     }
+
+    fn check_outdated(&self) -> std::collections::HashMap<String, OutdatedInfo> {
+        let packages = self.list_packages();
+        resolve_concurrently(&packages, |name| {
+            let info = fetch_pypi_info(name)?;
+            Some(OutdatedInfo {
+                latest: info.version,
+                description: info.summary,
+                checked_at: 0, // stamped by `outdated::refresh_outdated`
+            })
+        })
+    }
 }
 
 fn run_pip_command(pip_cmd: &str, args: &[&str]) -> Option<String> {
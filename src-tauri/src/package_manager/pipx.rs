@@ -0,0 +1,120 @@
+//! pipx package manager support
+
+use super::{PackageInfo, PackageManager};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::utils::command::command_no_window;
+
+pub struct PipxManager {
+    version: String,
+}
+
+#[derive(Deserialize)]
+struct PipxListOutput {
+    venvs: HashMap<String, PipxVenv>,
+}
+
+#[derive(Deserialize)]
+struct PipxVenv {
+    metadata: PipxMetadata,
+}
+
+#[derive(Deserialize)]
+struct PipxMetadata {
+    main_package: PipxMainPackage,
+}
+
+#[derive(Deserialize)]
+struct PipxMainPackage {
+    package_version: String,
+}
+
+impl PipxManager {
+    pub fn new() -> Option<Self> {
+        let output = run_pipx_command(&["--version"])?;
+        let version = output.trim().to_string();
+        Some(Self { version })
+    }
+}
+
+impl PackageManager for PipxManager {
+    fn name(&self) -> &str {
+        "pipx"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn get_version(&self) -> Option<String> {
+        Some(self.version.clone())
+    }
+
+    fn list_packages(&self) -> Vec<PackageInfo> {
+        let mut packages = Vec::new();
+
+        let output = match run_pipx_command(&["list", "--json"]) {
+            Some(o) => o,
+            None => return packages,
+        };
+
+        let list: PipxListOutput = match serde_json::from_str(&output) {
+            Ok(l) => l,
+            Err(_) => return packages,
+        };
+
+        for (name, venv) in list.venvs {
+            packages.push(PackageInfo {
+                name,
+                version: venv.metadata.main_package.package_version,
+                latest: None,
+                manager: "pipx".to_string(),
+                is_outdated: false,
+                description: None,
+            });
+        }
+
+        packages
+    }
+
+    fn update_package(&self, name: &str, _allow_major: bool) -> Result<String, String> {
+        // pipx doesn't surface a pre-upgrade version comparison here, so there's
+        // nothing to classify against - `allow_major` is a no-op for this manager.
+        match run_pipx_command(&["upgrade", name]) {
+            Some(output) => Ok(format!("Updated {} successfully:\n{}", name, output)),
+            None => Err(format!("Failed to update {}", name)),
+        }
+    }
+
+    fn uninstall_package(&self, name: &str) -> Result<String, String> {
+        match run_pipx_command(&["uninstall", name]) {
+            Some(output) => Ok(format!("Uninstalled {} successfully:\n{}", name, output)),
+            None => Err(format!("Failed to uninstall {}", name)),
+        }
+    }
+}
+
+fn run_pipx_command(args: &[&str]) -> Option<String> {
+    // On Windows, pipx is a script shim and needs to be run via cmd /C
+    #[cfg(target_os = "windows")]
+    let output = {
+        let pipx_args = std::iter::once("pipx")
+            .chain(args.iter().copied())
+            .collect::<Vec<_>>()
+            .join(" ");
+        command_no_window("cmd")
+            .args(["/C", &pipx_args])
+            .output()
+            .ok()?
+    };
+
+    #[cfg(not(target_os = "windows"))]
+    let output = command_no_window("pipx").args(args).output().ok()?;
+
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        None
+    }
+}
@@ -0,0 +1,211 @@
+//! NVD CVE metadata lookups, used to back a rule's static `risk_level` with
+//! an authoritative CVSS v3 base score when the rule cites one or more CVE
+//! IDs (see `PortRule::cve_ids`/`ConfigRule::cve_ids`).
+//!
+//! Unlike `advisories::osv`'s single cached blob per scan, entries here are
+//! cached keyed by CVE ID with their own `fetched_at` - a CVE looked up last
+//! week and one looked up just now don't need to share one global refetch,
+//! and a scan that only touches a few new IDs doesn't refetch ones it
+//! already knows. Falls back to the rule's static `risk_level` whenever NVD
+//! can't be reached and there's no usable cache entry.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::definitions::{risk_level_from_cvss, RiskLevel};
+
+const NVD_API_URL: &str = "https://services.nvd.nist.gov/rest/json/cves/2.0";
+const TTL_SECS: u64 = 7 * 24 * 60 * 60;
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// One CVE's authoritative metadata, as fetched from NVD.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CveRecord {
+    pub id: String,
+    pub base_score: f32,
+    pub vector: String,
+    pub published: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedCve {
+    record: CveRecord,
+    fetched_at: u64,
+}
+
+type CveCache = HashMap<String, CachedCve>;
+
+fn cache_path() -> PathBuf {
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .unwrap_or_default();
+    PathBuf::from(home)
+        .join(".dev-janitor")
+        .join("cache")
+        .join("cve_cache.json")
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn load_cache() -> CveCache {
+    fs::read_to_string(cache_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_cache(cache: &CveCache) {
+    let path = cache_path();
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(json) = serde_json::to_string(cache) {
+        let _ = fs::write(path, json);
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct NvdResponse {
+    #[serde(default)]
+    vulnerabilities: Vec<NvdVulnerability>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NvdVulnerability {
+    cve: NvdCve,
+}
+
+#[derive(Debug, Deserialize)]
+struct NvdCve {
+    id: String,
+    #[serde(default)]
+    published: String,
+    #[serde(default)]
+    metrics: NvdMetrics,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct NvdMetrics {
+    #[serde(rename = "cvssMetricV31", default)]
+    v31: Vec<NvdCvssMetric>,
+    #[serde(rename = "cvssMetricV30", default)]
+    v30: Vec<NvdCvssMetric>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NvdCvssMetric {
+    #[serde(rename = "cvssData")]
+    cvss_data: NvdCvssData,
+}
+
+#[derive(Debug, Deserialize)]
+struct NvdCvssData {
+    #[serde(rename = "baseScore")]
+    base_score: f32,
+    #[serde(rename = "vectorString")]
+    vector_string: String,
+}
+
+/// Query NVD for `cve_id` directly, bypassing the cache - the CVSS v3.1
+/// metric is preferred over v3.0 when both are present, same as NVD's own
+/// UI does.
+fn query_nvd(cve_id: &str) -> Option<CveRecord> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .build()
+        .ok()?;
+    let response: NvdResponse = client
+        .get(NVD_API_URL)
+        .query(&[("cveId", cve_id)])
+        .send()
+        .ok()?
+        .json()
+        .ok()?;
+
+    let cve = response.vulnerabilities.into_iter().next()?.cve;
+    let metric = cve.metrics.v31.first().or(cve.metrics.v30.first())?;
+
+    Some(CveRecord {
+        id: cve.id,
+        base_score: metric.cvss_data.base_score,
+        vector: metric.cvss_data.vector_string.clone(),
+        published: cve.published,
+    })
+}
+
+/// Look up one CVE ID, preferring the on-disk cache when it's still within
+/// the TTL and falling back to a live NVD lookup (persisting the result)
+/// otherwise. `None` when the ID isn't found, has no CVSS v3 metric, or NVD
+/// can't be reached and there's no usable cache entry.
+pub fn lookup(cve_id: &str) -> Option<CveRecord> {
+    let mut cache = load_cache();
+
+    if let Some(cached) = cache.get(cve_id) {
+        if now_secs().saturating_sub(cached.fetched_at) < TTL_SECS {
+            return Some(cached.record.clone());
+        }
+    }
+
+    let record = query_nvd(cve_id)?;
+    cache.insert(
+        cve_id.to_string(),
+        CachedCve { record: record.clone(), fetched_at: now_secs() },
+    );
+    save_cache(&cache);
+    Some(record)
+}
+
+/// Reconcile a rule's static `risk_level` against whatever `cve_ids` it
+/// cites: look each one up, take the highest-severity score found, and keep
+/// the more severe of that and `risk_level` - a live CVSS score is never
+/// allowed to downgrade a risk the rule author set deliberately, only raise
+/// it. Returns the resolved level plus, when a lookup actually succeeded, a
+/// detail line recording both the static and fetched severities so a
+/// disagreement is visible rather than silently resolved. `None` for the
+/// second element (and `risk_level` unchanged) when `cve_ids` is empty or
+/// every lookup failed - the all-offline case this is meant to degrade to.
+pub fn enrich(risk_level: RiskLevel, cve_ids: &[String]) -> (RiskLevel, Option<String>) {
+    let best = cve_ids.iter().filter_map(|id| lookup(id)).max_by(|a, b| {
+        a.base_score.partial_cmp(&b.base_score).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let Some(cve) = best else {
+        return (risk_level, None);
+    };
+
+    let fetched_level = risk_level_from_cvss(cve.base_score);
+    let resolved = if severity_rank(fetched_level) > severity_rank(risk_level) {
+        fetched_level
+    } else {
+        risk_level
+    };
+
+    let note = format!(
+        "{} CVSS {:.1} ({}) published {} - static risk {}, fetched risk {}",
+        cve.id,
+        cve.base_score,
+        cve.vector,
+        cve.published,
+        risk_level.as_str(),
+        fetched_level.as_str()
+    );
+
+    (resolved, Some(note))
+}
+
+fn severity_rank(level: RiskLevel) -> u8 {
+    match level {
+        RiskLevel::Critical => 3,
+        RiskLevel::High => 2,
+        RiskLevel::Medium => 1,
+        RiskLevel::Low => 0,
+    }
+}
@@ -0,0 +1,94 @@
+//! CVSS v3.x base-score calculation from a vector string
+//!
+//! NVD hands us a structured `baseScore` directly (see `cve.rs`), but both
+//! RustSec advisory-db and OSV.dev only record the CVSS vector string
+//! itself (e.g. `"CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"`) - the base
+//! score has to be derived from it per the CVSS v3.1 specification
+//! (<https://www.first.org/cvss/v3.1/specification-document>, section 7.4).
+
+/// Parse a CVSS v3.0/3.1 vector string and compute its base score.
+/// `None` for anything that isn't a v3.x vector, or that's missing one of
+/// the base metrics the formula needs.
+pub fn base_score_from_vector(vector: &str) -> Option<f32> {
+    if !vector.starts_with("CVSS:3.0/") && !vector.starts_with("CVSS:3.1/") {
+        return None;
+    }
+
+    let metrics: std::collections::HashMap<&str, &str> = vector
+        .split('/')
+        .skip(1)
+        .filter_map(|m| m.split_once(':'))
+        .collect();
+
+    let av = match *metrics.get("AV")? {
+        "N" => 0.85,
+        "A" => 0.62,
+        "L" => 0.55,
+        "P" => 0.2,
+        _ => return None,
+    };
+    let ac = match *metrics.get("AC")? {
+        "L" => 0.77,
+        "H" => 0.44,
+        _ => return None,
+    };
+    let scope_changed = match *metrics.get("S")? {
+        "U" => false,
+        "C" => true,
+        _ => return None,
+    };
+    let pr = match (*metrics.get("PR")?, scope_changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        ("H", false) => 0.27,
+        ("H", true) => 0.5,
+        _ => return None,
+    };
+    let ui = match *metrics.get("UI")? {
+        "N" => 0.85,
+        "R" => 0.62,
+        _ => return None,
+    };
+    let impact_metric = |key: &str| -> Option<f32> {
+        match *metrics.get(key)? {
+            "N" => Some(0.0),
+            "L" => Some(0.22),
+            "H" => Some(0.56),
+            _ => None,
+        }
+    };
+    let c = impact_metric("C")?;
+    let i = impact_metric("I")?;
+    let a = impact_metric("A")?;
+
+    let iss = 1.0 - ((1.0 - c) * (1.0 - i) * (1.0 - a));
+    let impact = if scope_changed {
+        7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powf(15.0)
+    } else {
+        6.42 * iss
+    };
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+
+    let exploitability = 8.22 * av * ac * pr * ui;
+    let base_score = if scope_changed {
+        1.08 * (impact + exploitability)
+    } else {
+        impact + exploitability
+    };
+
+    Some(roundup(base_score.min(10.0)))
+}
+
+/// CVSS's own "round up to the nearest 0.1" - not plain float rounding,
+/// since e.g. 4.0 must stay 4.0 while 4.01 must become 4.1 (spec section 7.4).
+fn roundup(value: f32) -> f32 {
+    let int_value = (value * 100_000.0).round() as i64;
+    if int_value % 10_000 == 0 {
+        int_value as f32 / 100_000.0
+    } else {
+        ((int_value / 10_000) + 1) as f32 / 10.0
+    }
+}
@@ -14,6 +14,20 @@ pub enum RiskLevel {
     Low,       // Informational - best practice recommendation
 }
 
+/// Map a CVSS v3 base score to the same risk bands used throughout the app:
+/// 9.0-10.0 Critical, 7.0-8.9 High, 4.0-6.9 Medium, below that Low.
+pub fn risk_level_from_cvss(score: f32) -> RiskLevel {
+    if score >= 9.0 {
+        RiskLevel::Critical
+    } else if score >= 7.0 {
+        RiskLevel::High
+    } else if score >= 4.0 {
+        RiskLevel::Medium
+    } else {
+        RiskLevel::Low
+    }
+}
+
 impl RiskLevel {
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -45,6 +59,21 @@ pub enum ConfigCheckType {
     FileMissing { path_pattern: String, pattern: String },
     /// Check environment variable
     EnvVar { name: String, insecure_value: Option<String> },
+    /// Parse a `tasks.json`-shaped file and flag tasks that auto-run on
+    /// folder open or invoke a shell network/download command, rather than
+    /// just substring-matching its raw text (see `scanner::audit_tasks_json`).
+    /// Flagged files whose content hash is already in the audit ledger (see
+    /// `ledger`) are suppressed instead of re-reported every scan.
+    JsonTaskAudit { path_pattern: String },
+    /// Scan a file for leaked credentials using the real per-provider regex
+    /// shapes in `get_secret_patterns`, falling back to a Shannon-entropy
+    /// gate over whitespace-delimited tokens for keys that don't match a
+    /// known prefix (see `scanner::scan_secret_in_content`) - unlike
+    /// `FileContains`, whose `pattern` is only ever matched as a plain
+    /// substring (or a `|`-separated list of them), so `"sk-ant-|sk-"`
+    /// can't distinguish an actual key from the same text in a comment, and
+    /// can't catch a rotated key format it wasn't written to expect.
+    SecretScan { path_pattern: String },
 }
 
 /// Port exposure rule
@@ -56,6 +85,11 @@ pub struct PortRule {
     pub risk_if_exposed: RiskLevel,
     /// Acceptable if bound to these addresses only
     pub safe_bindings: Vec<String>,
+    /// CVE IDs this rule tracks (e.g. `"CVE-2026-22812"`), if any - looked up
+    /// against NVD by `security_scan::cve::enrich` to back `risk_if_exposed`
+    /// with an authoritative CVSS score instead of a purely static one.
+    #[serde(default)]
+    pub cve_ids: Vec<String>,
 }
 
 /// Configuration vulnerability rule
@@ -66,6 +100,9 @@ pub struct ConfigRule {
     pub check: ConfigCheckType,
     pub risk_level: RiskLevel,
     pub remediation: String,
+    /// CVE IDs this rule tracks, if any - see `PortRule::cve_ids`.
+    #[serde(default)]
+    pub cve_ids: Vec<String>,
 }
 
 /// Security rule definition for an AI tool
@@ -99,6 +136,39 @@ pub struct SecurityFinding {
     pub risk_level: RiskLevel,
     pub remediation: String,
     pub details: String, // e.g., "Port 18789 bound to 0.0.0.0"
+    /// Machine-applicable version of `remediation`, when the check that
+    /// produced this finding located something precise enough to rewrite.
+    /// `None` for findings that only a human can act on (e.g. "update your
+    /// cargo crate" or "verify this port isn't reachable from the internet").
+    pub remediation_action: Option<Remediation>,
+}
+
+/// A structured, programmatically-applicable remediation for a `SecurityFinding`,
+/// modeled after how `rustfix`/`cargo fix` turn a diagnostic into a concrete
+/// edit. See `remediation::apply_remediation` for how each variant is applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Remediation {
+    /// Set an environment variable to a known-safe value
+    SetEnvVar { name: String, value: String },
+    /// Replace the first occurrence of `from` with `to` in `path`
+    ReplaceInFile { path: String, from: String, to: String },
+    /// Append `line` to `path` if it isn't already present
+    EnsureLineInFile { path: String, line: String },
+}
+
+/// Record of what `apply_remediation`/`apply_all` did (or, for a dry run,
+/// would do) for one finding.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedFix {
+    pub tool_id: String,
+    pub issue: String,
+    /// "previewed", "applied", or "error"
+    pub status: String,
+    /// Human-readable summary of the change - the `from` -> `to` span, the
+    /// line that was added, or the error message when `status == "error"`.
+    pub diff: String,
+    /// Path to the pre-edit backup, set only once a file has actually been written.
+    pub backup_path: Option<String>,
 }
 
 /// Result of a security scan
@@ -144,6 +214,7 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "Primary gateway port - should NOT be exposed to internet".into(),
                     risk_if_exposed: RiskLevel::Critical,
                     safe_bindings: vec!["127.0.0.1".into(), "localhost".into(), "::1".into()],
+                    cve_ids: vec![],
                 },
                 PortRule {
                     port: 18790,
@@ -151,6 +222,7 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "Admin web interface - exposes API keys and chat history".into(),
                     risk_if_exposed: RiskLevel::Critical,
                     safe_bindings: vec!["127.0.0.1".into(), "localhost".into(), "::1".into()],
+                    cve_ids: vec![],
                 },
             ],
             configs: vec![
@@ -163,16 +235,17 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     },
                     risk_level: RiskLevel::High,
                     remediation: "Set gateway.trustedProxies to only trusted reverse proxy IPs".into(),
+                    cve_ids: vec![],
                 },
                 ConfigRule {
                     name: "API Keys in Config".into(),
                     description: "Anthropic/OpenAI API keys stored in plaintext config".into(),
-                    check: ConfigCheckType::FileContains {
+                    check: ConfigCheckType::SecretScan {
                         path_pattern: "**/clawdbot.config.*".into(),
-                        pattern: "sk-ant-|sk-".into(),
                     },
                     risk_level: RiskLevel::High,
                     remediation: "Use environment variables for API keys instead of config files".into(),
+                    cve_ids: vec![],
                 },
             ],
             config_paths: vec![
@@ -198,6 +271,7 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "CVE-2026-22812: Unauthenticated HTTP server with CORS * - allows RCE from any website".into(),
                     risk_if_exposed: RiskLevel::Critical,
                     safe_bindings: vec!["127.0.0.1".into()],
+                    cve_ids: vec!["CVE-2026-22812".into()],
                 },
                 PortRule {
                     port: 4097,
@@ -205,6 +279,7 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "CVE-2026-22812: Alternative port when 4096 is in use".into(),
                     risk_if_exposed: RiskLevel::Critical,
                     safe_bindings: vec!["127.0.0.1".into()],
+                    cve_ids: vec!["CVE-2026-22812".into()],
                 },
                 PortRule {
                     port: 8765,
@@ -212,18 +287,19 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "Debug/MCP server - should be localhost only".into(),
                     risk_if_exposed: RiskLevel::High,
                     safe_bindings: vec!["127.0.0.1".into(), "localhost".into()],
+                    cve_ids: vec![],
                 },
             ],
             configs: vec![
                 ConfigRule {
                     name: "API Keys in Config".into(),
                     description: "API keys stored in opencode config".into(),
-                    check: ConfigCheckType::FileContains {
+                    check: ConfigCheckType::SecretScan {
                         path_pattern: "**/opencode.json".into(),
-                        pattern: "sk-".into(),
                     },
                     risk_level: RiskLevel::High,
                     remediation: "Use environment variables for API keys. Update to OpenCode >= 1.0.216 to fix CVE-2026-22812".into(),
+                    cve_ids: vec!["CVE-2026-22812".into()],
                 },
             ],
             config_paths: vec![
@@ -248,6 +324,7 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "Aider browser interface".into(),
                     risk_if_exposed: RiskLevel::Medium,
                     safe_bindings: vec!["127.0.0.1".into(), "localhost".into()],
+                    cve_ids: vec![],
                 },
             ],
             configs: vec![
@@ -260,6 +337,7 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     },
                     risk_level: RiskLevel::Medium,
                     remediation: "Use OPENAI_API_KEY or ANTHROPIC_API_KEY environment variables".into(),
+                    cve_ids: vec![],
                 },
             ],
             config_paths: vec![
@@ -284,6 +362,7 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "Chrome DevTools debug protocol port".into(),
                     risk_if_exposed: RiskLevel::Critical,
                     safe_bindings: vec!["127.0.0.1".into()],
+                    cve_ids: vec![],
                 },
             ],
             configs: vec![],
@@ -306,12 +385,12 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                 ConfigRule {
                     name: "OpenAI API Key in config".into(),
                     description: "API key stored in codex config".into(),
-                    check: ConfigCheckType::FileContains {
+                    check: ConfigCheckType::SecretScan {
                         path_pattern: "**/codex/config.*".into(),
-                        pattern: "sk-".into(),
                     },
                     risk_level: RiskLevel::Medium,
                     remediation: "Use OPENAI_API_KEY environment variable".into(),
+                    cve_ids: vec![],
                 },
             ],
             config_paths: vec![
@@ -336,6 +415,7 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "Continue's local model server".into(),
                     risk_if_exposed: RiskLevel::Medium,
                     safe_bindings: vec!["127.0.0.1".into(), "localhost".into()],
+                    cve_ids: vec![],
                 },
             ],
             configs: vec![],
@@ -361,6 +441,7 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "Node.js inspector port - allows remote code execution if exposed".into(),
                     risk_if_exposed: RiskLevel::Critical,
                     safe_bindings: vec!["127.0.0.1".into()],
+                    cve_ids: vec![],
                 },
             ],
             configs: vec![
@@ -373,6 +454,17 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     },
                     risk_level: RiskLevel::Medium,
                     remediation: "Enable Workspace Trust feature in Cursor settings. Audit .vscode/tasks.json in untrusted repos".into(),
+                    cve_ids: vec![],
+                },
+                ConfigRule {
+                    name: "Suspicious auto-run task in tasks.json".into(),
+                    description: "A VS Code/Cursor task either runs automatically on folder open (runOptions.runOn == \"folderOpen\") or invokes a shell network/download command - either is a known supply-chain vector for executing code the moment a malicious repo is opened".into(),
+                    check: ConfigCheckType::JsonTaskAudit {
+                        path_pattern: "**/tasks.json".into(),
+                    },
+                    risk_level: RiskLevel::Critical,
+                    remediation: "Review the task before opening this repo again. If it's legitimate, approve it with approve_config_file_cmd so future scans don't re-flag the same content".into(),
+                    cve_ids: vec![],
                 },
             ],
             config_paths: vec![
@@ -397,6 +489,7 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "AI language server port".into(),
                     risk_if_exposed: RiskLevel::Medium,
                     safe_bindings: vec!["127.0.0.1".into(), "localhost".into()],
+                    cve_ids: vec![],
                 },
             ],
             configs: vec![],
@@ -422,6 +515,7 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "Common MCP server port - check for auth and CORS settings".into(),
                     risk_if_exposed: RiskLevel::High,
                     safe_bindings: vec!["127.0.0.1".into()],
+                    cve_ids: vec![],
                 },
                 PortRule {
                     port: 8080,
@@ -429,18 +523,19 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                     description: "MCP HTTP server - verify authentication is enabled".into(),
                     risk_if_exposed: RiskLevel::High,
                     safe_bindings: vec!["127.0.0.1".into()],
+                    cve_ids: vec![],
                 },
             ],
             configs: vec![
                 ConfigRule {
                     name: "API Keys in MCP Config".into(),
                     description: "Credentials exposed via environment variables or config".into(),
-                    check: ConfigCheckType::FileContains {
+                    check: ConfigCheckType::SecretScan {
                         path_pattern: "**/mcp.json".into(),
-                        pattern: "sk-|api_key|apiKey|API_KEY".into(),
                     },
                     risk_level: RiskLevel::Critical,
                     remediation: "Use secret management. Never store API keys in MCP config files".into(),
+                    cve_ids: vec![],
                 },
             ],
             config_paths: vec![
@@ -463,12 +558,12 @@ pub fn get_ai_tool_rules() -> Vec<AiToolSecurityRule> {
                 ConfigRule {
                     name: "API Keys in Config".into(),
                     description: "Google API keys stored in config".into(),
-                    check: ConfigCheckType::FileContains {
+                    check: ConfigCheckType::SecretScan {
                         path_pattern: "**/settings.json".into(),
-                        pattern: "AIza".into(),
                     },
                     risk_level: RiskLevel::Medium,
                     remediation: "Use GOOGLE_API_KEY environment variable or gcloud auth".into(),
+                    cve_ids: vec![],
                 },
             ],
             config_paths: vec![
@@ -493,3 +588,52 @@ pub static AI_TOOL_SECURITY_RULES: &[AiToolSecurityRule] = &[];
 pub fn get_rules() -> &'static Vec<AiToolSecurityRule> {
     ai_tool_security_rules()
 }
+
+/// A plaintext-secret pattern to look for inside AI tool config files.
+/// To add support for a new provider's key format, add an entry here -
+/// no scanner code changes needed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretPatternRule {
+    pub name: String,
+    /// Regex matched against raw file content
+    pub pattern: String,
+}
+
+/// ========================================
+/// SECRET PATTERNS - ADD NEW PROVIDER KEY FORMATS HERE
+/// ========================================
+fn build_secret_patterns() -> Vec<SecretPatternRule> {
+    vec![
+        SecretPatternRule {
+            name: "Anthropic API Key".into(),
+            pattern: r"sk-ant-[A-Za-z0-9_-]{20,}".into(),
+        },
+        SecretPatternRule {
+            name: "OpenAI API Key".into(),
+            pattern: r"sk-[A-Za-z0-9]{20,}".into(),
+        },
+        SecretPatternRule {
+            name: "Google API Key".into(),
+            pattern: r"AIza[0-9A-Za-z_-]{35}".into(),
+        },
+        SecretPatternRule {
+            name: "AWS Access Key ID".into(),
+            pattern: r"AKIA[0-9A-Z]{16}".into(),
+        },
+        SecretPatternRule {
+            name: "GitHub Personal Access Token".into(),
+            pattern: r"ghp_[A-Za-z0-9]{36}".into(),
+        },
+        SecretPatternRule {
+            name: "Generic Bearer Token".into(),
+            pattern: r"Bearer [A-Za-z0-9._-]{20,}".into(),
+        },
+    ]
+}
+
+/// Get the secret-pattern rule table used to scan AI tool config file contents
+pub fn get_secret_patterns() -> &'static Vec<SecretPatternRule> {
+    use std::sync::OnceLock;
+    static PATTERNS: OnceLock<Vec<SecretPatternRule>> = OnceLock::new();
+    PATTERNS.get_or_init(build_secret_patterns)
+}
@@ -0,0 +1,74 @@
+//! On-disk audit ledger backing `ConfigCheckType::JsonTaskAudit` - a TOML
+//! file at `~/.dev-janitor/config_audit_ledger.toml` mapping an audited
+//! file's path to the SHA-256 hash of the content a human last approved,
+//! in the spirit of a `cargo-vet` audit store: a flagged file whose current
+//! hash matches its ledger entry is a file someone already reviewed and is
+//! suppressed instead of being re-reported every scan, while any change to
+//! its content (a different hash) drops it back out of the ledger's cover
+//! and it's flagged again until re-approved.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use super::scanner::get_home_dir;
+
+fn ledger_path() -> Option<PathBuf> {
+    get_home_dir().map(|h| h.join(".dev-janitor").join("config_audit_ledger.toml"))
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Ledger {
+    #[serde(default)]
+    approved: HashMap<String, String>,
+}
+
+fn load() -> Ledger {
+    let Some(path) = ledger_path() else {
+        return Ledger::default();
+    };
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|s| toml::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save(ledger: &Ledger) {
+    let Some(path) = ledger_path() else {
+        return;
+    };
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(text) = toml::to_string_pretty(ledger) {
+        let _ = fs::write(path, text);
+    }
+}
+
+/// SHA-256 of `content`, hex-encoded - the identity a ledger entry is keyed
+/// against.
+pub fn hash_content(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Has `path`'s current content (already hashed as `content_hash`) already
+/// been approved? `false` for a path with no ledger entry, or one whose
+/// entry no longer matches - content drift revokes approval.
+pub fn is_approved(path: &str, content_hash: &str) -> bool {
+    load().approved.get(path).is_some_and(|h| h == content_hash)
+}
+
+/// Record `path`'s current on-disk content as approved, so the next scan
+/// that sees the same bytes suppresses the finding instead of re-flagging
+/// it. Re-approving after the file changes overwrites the old hash.
+pub fn approve_file(path: &str) -> Result<(), String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let mut ledger = load();
+    ledger.approved.insert(path.to_string(), hash_content(&content));
+    save(&ledger);
+    Ok(())
+}
@@ -3,14 +3,24 @@
 //!
 //! To add a new tool, simply add a new entry to `get_ai_tool_rules()` in definitions.rs
 
+pub mod cve;
+pub mod cvss;
 mod definitions;
+pub mod ledger;
+pub mod remediation;
+pub mod report;
 pub mod scanner;
 
 pub use definitions::{
-    AiToolSecurityRule, ConfigCheckType, ConfigRule, PortRule, RiskLevel, SecurityFinding,
-    SecurityScanResult, SecuritySummary, get_rules, get_ai_tool_rules,
+    AiToolSecurityRule, AppliedFix, ConfigCheckType, ConfigRule, PortRule, Remediation, RiskLevel,
+    SecretPatternRule, SecurityFinding, SecurityScanResult, SecuritySummary, get_rules,
+    get_ai_tool_rules, get_secret_patterns, risk_level_from_cvss,
 };
+pub use cvss::base_score_from_vector;
+pub use ledger::approve_file as approve_config_file;
+pub use remediation::{apply_all, apply_remediation};
+pub use report::{render, OutputFormat};
 pub use scanner::{
     check_config_files, check_exposed_ports, get_security_findings, scan_ai_tool_security,
-    scan_specific_tool,
+    scan_config_secrets, scan_specific_tool, scan_supply_chain,
 };
@@ -0,0 +1,160 @@
+//! Applies the structured `Remediation` a `SecurityFinding` carries
+//!
+//! Every edit backs up the file it touches before writing, and every function
+//! here takes a `dry_run` flag rather than prompting - same as the
+//! `allow_major` gate on `PackageManager::update_package`, callers decide
+//! whether to preview or commit, and an actual confirmation dialog (if any)
+//! lives in the UI layer above this.
+
+use std::fs;
+use std::path::Path;
+
+use super::definitions::{AppliedFix, Remediation, SecurityFinding};
+
+/// Apply (or, with `dry_run`, just preview) one finding's remediation action.
+/// Returns an error if the finding has no structured action, or if the file
+/// it targets no longer matches what the action expects.
+pub fn apply_remediation(finding: &SecurityFinding, dry_run: bool) -> Result<AppliedFix, String> {
+    let action = finding
+        .remediation_action
+        .as_ref()
+        .ok_or_else(|| format!("{} has no structured remediation available", finding.issue))?;
+
+    match action {
+        Remediation::SetEnvVar { name, value } => {
+            if !dry_run {
+                std::env::set_var(name, value);
+            }
+            Ok(AppliedFix {
+                tool_id: finding.tool_id.clone(),
+                issue: finding.issue.clone(),
+                status: status_for(dry_run),
+                diff: format!("{}={}", name, value),
+                backup_path: None,
+            })
+        }
+        Remediation::ReplaceInFile { path, from, to } => {
+            let content = read_target(path)?;
+            if !content.contains(from.as_str()) {
+                return Err(format!("{} no longer contains the expected text in {}", finding.issue, path));
+            }
+            // `from` is typically the whole line a secret was found on (see
+            // `redact_line_remediation`), so the diff can't echo it verbatim
+            // without defeating the point of the redaction.
+            let diff = format!("- {}\n+ {}", redact_for_diff(from), to);
+
+            if dry_run {
+                return Ok(preview(finding, diff));
+            }
+
+            let backup_path = backup_file(path, &content)?;
+            let updated = content.replacen(from.as_str(), to, 1);
+            fs::write(path, updated).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+            Ok(applied(finding, diff, backup_path))
+        }
+        Remediation::EnsureLineInFile { path, line } => {
+            let content = read_target(path)?;
+            if content.lines().any(|l| l.trim() == line.trim()) {
+                return Err(format!("{} is already present in {}", line, path));
+            }
+            let diff = format!("+ {}", line);
+
+            if dry_run {
+                return Ok(preview(finding, diff));
+            }
+
+            let backup_path = backup_file(path, &content)?;
+            let mut updated = content;
+            if !updated.is_empty() && !updated.ends_with('\n') {
+                updated.push('\n');
+            }
+            updated.push_str(line);
+            updated.push('\n');
+            fs::write(path, updated).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+            Ok(applied(finding, diff, backup_path))
+        }
+    }
+}
+
+/// Apply (or preview) every finding's remediation action. Unlike
+/// `apply_remediation`, a finding with no action or a failed edit doesn't
+/// abort the batch - it comes back with `status: "error"` so one bad finding
+/// doesn't block the rest, the same way `ai_cli::run_batch` reports per-tool
+/// failures instead of failing the whole batch.
+pub fn apply_all(findings: &[SecurityFinding], dry_run: bool) -> Vec<AppliedFix> {
+    findings
+        .iter()
+        .map(|finding| {
+            apply_remediation(finding, dry_run).unwrap_or_else(|err| AppliedFix {
+                tool_id: finding.tool_id.clone(),
+                issue: finding.issue.clone(),
+                status: "error".to_string(),
+                diff: err,
+                backup_path: None,
+            })
+        })
+        .collect()
+}
+
+fn status_for(dry_run: bool) -> String {
+    if dry_run { "previewed" } else { "applied" }.to_string()
+}
+
+fn preview(finding: &SecurityFinding, diff: String) -> AppliedFix {
+    AppliedFix {
+        tool_id: finding.tool_id.clone(),
+        issue: finding.issue.clone(),
+        status: "previewed".to_string(),
+        diff,
+        backup_path: None,
+    }
+}
+
+fn applied(finding: &SecurityFinding, diff: String, backup_path: String) -> AppliedFix {
+    AppliedFix {
+        tool_id: finding.tool_id.clone(),
+        issue: finding.issue.clone(),
+        status: "applied".to_string(),
+        diff,
+        backup_path: Some(backup_path),
+    }
+}
+
+fn read_target(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))
+}
+
+/// Shorten a line that may contain a live secret down to a safe-to-display
+/// preview before it goes into an `AppliedFix.diff` - mirrors
+/// `scanner::redact_preview`'s truncate-and-report-length approach.
+fn redact_for_diff(line: &str) -> String {
+    let visible: String = line.trim().chars().take(10).collect();
+    format!("{}...redacted ({} chars)", visible, line.trim().len())
+}
+
+/// Copy the pre-edit file contents to a sibling `.devjanitor-bak` file so a
+/// user can manually revert if the rewrite turns out wrong. The backup can
+/// carry the same secret the original did, so it's locked down to the owner
+/// the same way `check_overly_permissive_mode` flags a config file for not
+/// being.
+///
+/// A `.devjanitor-bak` that already exists is left alone rather than
+/// overwritten, so the first fix applied to a file keeps the true original
+/// on disk even if a later fix touches that same file again.
+fn backup_file(path: &str, content: &str) -> Result<String, String> {
+    let backup_path = format!("{}.devjanitor-bak", path);
+    if Path::new(&backup_path).exists() {
+        return Ok(backup_path);
+    }
+    fs::write(&backup_path, content).map_err(|e| format!("Failed to back up {}: {}", path, e))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let perms = fs::Permissions::from_mode(0o600);
+        fs::set_permissions(&backup_path, perms)
+            .map_err(|e| format!("Failed to lock down backup {}: {}", backup_path, e))?;
+    }
+
+    Ok(backup_path)
+}
@@ -0,0 +1,187 @@
+//! Export a `SecurityScanResult` in formats other consumers expect, rather
+//! than forcing every caller onto the crate's own serde shape.
+//!
+//! `Json` is that shape as-is (pretty-printed). `SimpleJson` flattens it down
+//! to just the findings plus the severity counts, for feeds that only want a
+//! quick pass/fail signal. `Sarif` emits SARIF 2.1.0 so the result can be fed
+//! to GitHub code scanning or any other SARIF-aware dashboard.
+
+use serde::{Deserialize, Serialize};
+
+use super::definitions::{RiskLevel, SecurityFinding, SecurityScanResult, SecuritySummary};
+
+/// Which shape `render` should produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutputFormat {
+    Json,
+    SimpleJson,
+    Sarif,
+}
+
+/// Render `result` as `fmt`. Falls back to `"{}"` if serialization somehow
+/// fails - none of these shapes can actually fail to serialize (no maps with
+/// non-string keys, no floats that could be NaN), but `render` returning a
+/// bare `String` rather than a `Result` means there's nowhere else to put it.
+pub fn render(result: &SecurityScanResult, fmt: OutputFormat) -> String {
+    let rendered = match fmt {
+        OutputFormat::Json => serde_json::to_string_pretty(result),
+        OutputFormat::SimpleJson => serde_json::to_string_pretty(&SimpleJsonReport::from(result)),
+        OutputFormat::Sarif => serde_json::to_string_pretty(&Sarif::from(result)),
+    };
+    rendered.unwrap_or_else(|_| "{}".to_string())
+}
+
+#[derive(Debug, Serialize)]
+struct SimpleJsonReport<'a> {
+    findings: &'a [SecurityFinding],
+    summary: &'a SecuritySummary,
+}
+
+impl<'a> From<&'a SecurityScanResult> for SimpleJsonReport<'a> {
+    fn from(result: &'a SecurityScanResult) -> Self {
+        SimpleJsonReport { findings: &result.findings, summary: &result.summary }
+    }
+}
+
+fn sarif_level(risk_level: RiskLevel) -> &'static str {
+    match risk_level {
+        RiskLevel::Critical | RiskLevel::High => "error",
+        RiskLevel::Medium => "warning",
+        RiskLevel::Low => "note",
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SarifText {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRule {
+    id: String,
+    name: String,
+    #[serde(rename = "shortDescription")]
+    short_description: SarifText,
+    #[serde(rename = "fullDescription")]
+    full_description: SarifText,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifDriver {
+    name: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifPhysicalLocation {
+    #[serde(rename = "artifactLocation")]
+    artifact_location: SarifArtifactLocation,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifLocation {
+    #[serde(rename = "physicalLocation")]
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifResult {
+    #[serde(rename = "ruleId")]
+    rule_id: String,
+    level: &'static str,
+    message: SarifText,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+}
+
+/// Config-file findings phrase `details` around the path they found the
+/// issue in ("Found in: ...", "File exists: ...", "Missing '...' in: ...");
+/// pull that back out so the SARIF result can carry a real `artifactLocation`
+/// instead of leaving every result unlocated. Port findings have no file to
+/// point at (`details` is process/connection info), so this is `None` for
+/// those - a result with no `locations` is still valid SARIF, just less
+/// useful to a dashboard that expects one.
+fn location_from_details(details: &str) -> Option<SarifLocation> {
+    let path = details
+        .strip_prefix("Found in: ")
+        .or_else(|| details.strip_prefix("File exists: "))
+        .or_else(|| details.rsplit_once(" in: ").map(|(_, path)| path))?;
+    Some(SarifLocation {
+        physical_location: SarifPhysicalLocation {
+            artifact_location: SarifArtifactLocation { uri: path.to_string() },
+        },
+    })
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct Sarif {
+    version: &'static str,
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+/// A finding's `tool_id` plus its `issue` text (the rule's own name, e.g. a
+/// `ConfigRule::name` or a port check's generated title) uniquely identifies
+/// the check that produced it - `SecurityScanResult` only carries findings,
+/// not the rule catalog they came from, so this is the one `rule`-per-check
+/// identity available to build SARIF's `rules` array from.
+fn rule_id(finding: &SecurityFinding) -> String {
+    format!("{}/{}", finding.tool_id, finding.issue)
+}
+
+impl From<&SecurityScanResult> for Sarif {
+    fn from(result: &SecurityScanResult) -> Self {
+        let mut rules: Vec<SarifRule> = Vec::new();
+        for finding in &result.findings {
+            let id = rule_id(finding);
+            if rules.iter().any(|r| r.id == id) {
+                continue;
+            }
+            rules.push(SarifRule {
+                id: id.clone(),
+                name: finding.issue.clone(),
+                short_description: SarifText { text: finding.issue.clone() },
+                full_description: SarifText { text: finding.description.clone() },
+            });
+        }
+
+        let results = result
+            .findings
+            .iter()
+            .map(|finding| SarifResult {
+                rule_id: rule_id(finding),
+                level: sarif_level(finding.risk_level),
+                message: SarifText { text: format!("{} ({})", finding.description, finding.details) },
+                locations: location_from_details(&finding.details).into_iter().collect(),
+            })
+            .collect();
+
+        Sarif {
+            version: "2.1.0",
+            schema: "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver { name: "Dev-Janitor", rules },
+                },
+                results,
+            }],
+        }
+    }
+}
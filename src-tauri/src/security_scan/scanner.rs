@@ -3,20 +3,42 @@
 //! This module implements the actual scanning functionality using the rules
 //! defined in definitions.rs
 
+use crate::advisories::{scan_cargo_advisories, scan_pip_advisories};
+use crate::package_manager::discover_managers;
 use crate::services::{get_ports_in_use, PortInfo};
 use chrono::Local;
+use std::collections::HashMap;
 use std::env;
 use std::fs;
-use std::net::TcpStream;
-use std::path::PathBuf;
+use std::net::{IpAddr, TcpStream, ToSocketAddrs, UdpSocket};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use sysinfo::System;
 
+use regex::Regex;
+
+use super::cve;
+use super::ledger;
 use super::definitions::{
-    AiToolSecurityRule, ConfigCheckType, RiskLevel, SecurityFinding,
-    SecurityScanResult, SecuritySummary, get_rules,
+    AiToolSecurityRule, ConfigCheckType, ConfigRule, Remediation, RiskLevel, SecretPatternRule,
+    SecurityFinding, SecurityScanResult, SecuritySummary, get_rules, get_secret_patterns,
 };
 
+/// Reconcile `finding.risk_level` against `cve_ids` via `cve::enrich`, and
+/// fold the result back into the finding - a no-op when the rule cites no
+/// CVE IDs, so callers can call this unconditionally on every finding a
+/// `PortRule`/`ConfigRule` check produces.
+fn enrich_with_cves(finding: &mut SecurityFinding, cve_ids: &[String]) {
+    if cve_ids.is_empty() {
+        return;
+    }
+    let (resolved, note) = cve::enrich(finding.risk_level, cve_ids);
+    finding.risk_level = resolved;
+    if let Some(note) = note {
+        finding.details = format!("{} | {}", finding.details, note);
+    }
+}
+
 /// Check if a port is actively listening and potentially exposed
 fn check_port_binding(port: u16) -> Option<String> {
     // Try to connect to the port to see if something is listening
@@ -32,8 +54,82 @@ fn check_port_binding(port: u16) -> Option<String> {
     None
 }
 
+/// A non-loopback address for this host, picked the way the OS itself would
+/// route a packet: "connect" a UDP socket to a public address (no bytes are
+/// actually sent for a connectionless protocol) and read back which local
+/// interface it bound to. Callers resolve this once per scan and pass it
+/// down, rather than caching it - the host's LAN-facing address can change
+/// between scans (new Wi-Fi network, VPN toggled) even if not within one.
+fn host_non_loopback_ip() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("8.8.8.8:80").ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}
+
+/// Unlike `check_port_binding`, which only proves a port answers on
+/// 127.0.0.1, this connects to `ip` - the host's LAN-facing address - to
+/// confirm the socket itself isn't loopback-restricted. A hit here doesn't
+/// prove a remote device can reach it (a firewall rule on the external
+/// interface could still block that; a self-connect to our own address can
+/// get routed locally regardless), but it does rule out "only ever
+/// reachable from this machine".
+fn check_external_reachability(ip: IpAddr, port: u16) -> Option<String> {
+    let addr = (ip, port).to_socket_addrs().ok()?.next()?;
+    TcpStream::connect_timeout(&addr, Duration::from_millis(200)).ok()?;
+    Some(format!("Accepted a connection via {}", ip))
+}
+
+/// Best-effort scrape of a bind address out of `PortInfo::state` (e.g.
+/// "LISTEN 0.0.0.0:5432") - the lister only gives us that as a free-form
+/// string, not a parsed address, so this looks for the first whitespace-
+/// separated token whose host portion is a real IP (or "localhost").
+fn extract_bind_address(state: &str) -> Option<String> {
+    state.split_whitespace().find_map(|token| {
+        let host = token.rsplit_once(':').map(|(h, _)| h).unwrap_or(token);
+        let host = host.trim_matches(|c| c == '[' || c == ']');
+        if host.eq_ignore_ascii_case("localhost") || host.parse::<IpAddr>().is_ok() {
+            Some(host.to_string())
+        } else {
+            None
+        }
+    })
+}
+
+/// One tier down from a rule's configured severity - used for findings that
+/// corroborate a rule (port is active/reachable) without the certainty of
+/// the binding-based "exposed" check.
+fn one_tier_down(risk_if_exposed: RiskLevel) -> RiskLevel {
+    match risk_if_exposed {
+        RiskLevel::Critical => RiskLevel::High,
+        RiskLevel::High => RiskLevel::Medium,
+        RiskLevel::Medium | RiskLevel::Low => RiskLevel::Low,
+    }
+}
+
+/// Is `bind_address` one of `safe_bindings`, or otherwise loopback-only?
+/// `0.0.0.0`/`::` are never safe even if a rule's `safe_bindings` happens to
+/// list them, since that's every interface, not a specific one. An address
+/// we couldn't determine is treated as unsafe rather than assumed fine.
+fn is_safe_binding(bind_address: Option<&str>, safe_bindings: &[String]) -> bool {
+    let Some(bind_address) = bind_address else {
+        return false;
+    };
+    if bind_address == "0.0.0.0" || bind_address == "::" {
+        return false;
+    }
+    if bind_address.eq_ignore_ascii_case("localhost") {
+        return true;
+    }
+    if let Ok(ip) = bind_address.parse::<IpAddr>() {
+        if ip.is_loopback() {
+            return true;
+        }
+    }
+    safe_bindings.iter().any(|safe| safe.eq_ignore_ascii_case(bind_address))
+}
+
 /// Get home directory cross-platform
-fn get_home_dir() -> Option<PathBuf> {
+pub(super) fn get_home_dir() -> Option<PathBuf> {
     #[cfg(target_os = "windows")]
     {
         env::var("USERPROFILE").ok().map(PathBuf::from)
@@ -44,26 +140,33 @@ fn get_home_dir() -> Option<PathBuf> {
     }
 }
 
-/// Check exposed ports for a tool
+/// Check exposed ports for a tool. `host_ip` is the host's LAN-facing
+/// address (see `host_non_loopback_ip`), resolved once by the caller and
+/// shared across every tool/rule in one scan.
 pub fn check_exposed_ports(
     tool: &AiToolSecurityRule,
     ports_info: &[PortInfo],
+    host_ip: Option<IpAddr>,
 ) -> Vec<SecurityFinding> {
     let mut findings = Vec::new();
 
     for port_rule in &tool.ports {
-        // Check if the port is in use
+        // Check if the port is in use. Exact port-number match, not a
+        // substring check on formatted finding text, so port 22 can't be
+        // mistaken for already-covered by a port-2222 finding.
+        let mut port_in_use = false;
+        let mut already_flagged = false;
+
         for p in ports_info {
             if p.port == port_rule.port {
+                port_in_use = true;
                 // Port is in use - check if it's safely bound
-                let is_safe = port_rule.safe_bindings.iter().any(|safe| {
-                    // This is simplified - in reality we'd check the actual binding address
-                    p.process_name.to_lowercase().contains("localhost")
-                        || p.state.contains("127.0.0.1")
-                });
+                let bind_address = extract_bind_address(&p.state);
+                let is_safe = is_safe_binding(bind_address.as_deref(), &port_rule.safe_bindings);
 
                 if !is_safe {
-                    findings.push(SecurityFinding {
+                    already_flagged = true;
+                    let mut finding = SecurityFinding {
                         tool_id: tool.id.clone(),
                         tool_name: tool.name.clone(),
                         issue: format!("Port {} ({}) is exposed", port_rule.port, port_rule.name),
@@ -77,19 +180,27 @@ pub fn check_exposed_ports(
                             "Process: {}, State: {}, PID: {}",
                             p.process_name, p.state, p.pid
                         ),
-                    });
+                        remediation_action: None,
+                    };
+                    enrich_with_cves(&mut finding, &port_rule.cve_ids);
+                    findings.push(finding);
                 }
             }
         }
 
         // Also try direct connection check
-        if let Some(status) = check_port_binding(port_rule.port) {
-            // Port is listening - warn even if we couldn't determine exposure
-            let already_reported = findings
-                .iter()
-                .any(|f| f.issue.contains(&port_rule.port.to_string()));
+        let loopback_status = check_port_binding(port_rule.port);
+        port_in_use = port_in_use || loopback_status.is_some();
 
-            if !already_reported {
+        if let Some(status) = &loopback_status {
+            // Port is listening - warn even if we couldn't determine exposure.
+            // Deliberately not run through enrich_with_cves: the tier-down
+            // here reflects lower confidence in the finding itself (we only
+            // know *something* answered on localhost, not that it's
+            // exposed), and a live CVSS score doesn't change that - it would
+            // just re-escalate a severity this check intentionally hedged.
+            if !already_flagged {
+                already_flagged = true;
                 findings.push(SecurityFinding {
                     tool_id: tool.id.clone(),
                     tool_name: tool.name.clone(),
@@ -98,24 +209,109 @@ pub fn check_exposed_ports(
                         port_rule.port, port_rule.name
                     ),
                     description: port_rule.description.clone(),
-                    risk_level: if port_rule.risk_if_exposed == RiskLevel::Critical {
-                        RiskLevel::High
-                    } else {
-                        RiskLevel::Medium
-                    },
+                    risk_level: one_tier_down(port_rule.risk_if_exposed),
                     remediation: format!(
                         "Verify {} is only accessible from trusted networks",
                         port_rule.name
                     ),
-                    details: status,
+                    details: status.clone(),
+                    remediation_action: None,
                 });
             }
         }
+
+        // Only bother probing the LAN-facing address if we already know
+        // something is listening on this port at all - skips a live connect
+        // attempt (and the IDS/log noise that comes with one) against every
+        // rule's port on hosts where most of them aren't in use. Trade-off:
+        // a service bound only to a LAN address (never 0.0.0.0, never
+        // 127.0.0.1) that the process lister fails to surface would be
+        // missed entirely - an accepted gap given that's a narrow case and
+        // the common "bound to all interfaces" one is already caught above.
+        if !port_in_use || already_flagged {
+            continue;
+        }
+
+        // A connection accepted on the host's LAN-facing address, rather
+        // than just 127.0.0.1, means the listener isn't loopback-restricted.
+        // That's worth its own finding distinct from the "exposed"/"active"
+        // ones above, but the severity stays one notch below them since a
+        // self-connect to our own address can get routed locally even when
+        // an external firewall would in fact block it - this check proves
+        // the socket isn't loopback-only, not that it's reachable remotely.
+        // Respect the rule's allowlist here too - a LAN/VPN address a rule
+        // explicitly trusts in safe_bindings shouldn't get re-flagged just
+        // because it's non-loopback.
+        let host_is_trusted = host_ip.is_some_and(|ip| is_safe_binding(Some(&ip.to_string()), &port_rule.safe_bindings));
+        if host_is_trusted {
+            continue;
+        }
+        // Not run through enrich_with_cves for the same reason as the
+        // "is active" finding above - the tier-down reflects this check's
+        // own evidentiary limits (a self-connect proves non-loopback, not
+        // remote reachability), which a live CVSS score has no bearing on.
+        if let Some(reached_via) = host_ip.and_then(|ip| check_external_reachability(ip, port_rule.port)) {
+            findings.push(SecurityFinding {
+                tool_id: tool.id.clone(),
+                tool_name: tool.name.clone(),
+                issue: format!(
+                    "Port {} ({}) is not loopback-restricted",
+                    port_rule.port, port_rule.name
+                ),
+                description: format!(
+                    "{} accepted a connection on a non-loopback address - it isn't limited to this machine, so anyone who can route to that address may be able to reach it (firewall rules permitting).",
+                    port_rule.name
+                ),
+                risk_level: one_tier_down(port_rule.risk_if_exposed),
+                remediation: format!(
+                    "Bind {} to 127.0.0.1 only, or block LAN/internet access to it with a firewall",
+                    port_rule.name
+                ),
+                details: reached_via,
+                remediation_action: None,
+            });
+        }
     }
 
     findings
 }
 
+/// True for `.json` config files, where our line-oriented rewrites (a `#`
+/// comment, a bare appended line) would produce invalid JSON rather than
+/// actually changing the setting.
+fn is_json_path(path: &str) -> bool {
+    Path::new(path).extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
+/// Build a `ReplaceInFile` remediation that redacts the whole line a secret
+/// pattern was found on, rather than just the matched marker substring (e.g.
+/// `sk-`) - replacing only the marker would leave most of the actual key
+/// behind. `None` if the pattern couldn't be pinned to a single line, or the
+/// file is JSON (see `is_json_path`).
+fn redact_line_remediation(path: &str, content: &str, pattern: &str) -> Option<Remediation> {
+    if is_json_path(path) {
+        return None;
+    }
+    let line = content.lines().find(|l| l.contains(pattern))?;
+    Some(Remediation::ReplaceInFile {
+        path: path.to_string(),
+        from: line.to_string(),
+        to: "# [REDACTED_BY_DEV_JANITOR] secret removed - use an environment variable instead".to_string(),
+    })
+}
+
+/// Build an `EnsureLineInFile` remediation for a missing security setting.
+/// Skipped for JSON files (see `is_json_path`).
+fn ensure_line_remediation(path: &str, line: &str) -> Option<Remediation> {
+    if is_json_path(path) {
+        return None;
+    }
+    Some(Remediation::EnsureLineInFile {
+        path: path.to_string(),
+        line: line.to_string(),
+    })
+}
+
 /// Check config files for security issues
 pub fn check_config_files(tool: &AiToolSecurityRule) -> Vec<SecurityFinding> {
     let mut findings = Vec::new();
@@ -124,6 +320,13 @@ pub fn check_config_files(tool: &AiToolSecurityRule) -> Vec<SecurityFinding> {
         None => return findings,
     };
 
+    // Compiled once per call rather than per `SecretScan` rule/file, same as
+    // `scan_config_secrets` does for the same pattern table.
+    let secret_patterns: Vec<(&SecretPatternRule, Regex)> = get_secret_patterns()
+        .iter()
+        .filter_map(|rule| Regex::new(&rule.pattern).ok().map(|re| (rule, re)))
+        .collect();
+
     for config_rule in &tool.configs {
         match &config_rule.check {
             ConfigCheckType::FileContains { path_pattern: _, pattern } => {
@@ -140,15 +343,19 @@ pub fn check_config_files(tool: &AiToolSecurityRule) -> Vec<SecurityFinding> {
                                         let patterns: Vec<&str> = pattern.split('|').collect();
                                         for p in patterns {
                                             if content.contains(p) {
-                                                findings.push(SecurityFinding {
+                                                let entry_path = entry.path().display().to_string();
+                                                let mut finding = SecurityFinding {
                                                     tool_id: tool.id.clone(),
                                                     tool_name: tool.name.clone(),
                                                     issue: config_rule.name.clone(),
                                                     description: config_rule.description.clone(),
                                                     risk_level: config_rule.risk_level,
                                                     remediation: config_rule.remediation.clone(),
-                                                    details: format!("Found in: {}", entry.path().display()),
-                                                });
+                                                    details: format!("Found in: {}", entry_path),
+                                                    remediation_action: redact_line_remediation(&entry_path, &content, p),
+                                                };
+                                                enrich_with_cves(&mut finding, &config_rule.cve_ids);
+                                                findings.push(finding);
                                                 break;
                                             }
                                         }
@@ -157,17 +364,21 @@ pub fn check_config_files(tool: &AiToolSecurityRule) -> Vec<SecurityFinding> {
                             }
                         } else if let Ok(content) = fs::read_to_string(&full_path) {
                             let patterns: Vec<&str> = pattern.split('|').collect();
+                            let full_path_str = full_path.display().to_string();
                             for p in patterns {
                                 if content.contains(p) {
-                                    findings.push(SecurityFinding {
+                                    let mut finding = SecurityFinding {
                                         tool_id: tool.id.clone(),
                                         tool_name: tool.name.clone(),
                                         issue: config_rule.name.clone(),
                                         description: config_rule.description.clone(),
                                         risk_level: config_rule.risk_level,
                                         remediation: config_rule.remediation.clone(),
-                                        details: format!("Found in: {}", full_path.display()),
-                                    });
+                                        details: format!("Found in: {}", full_path_str),
+                                        remediation_action: redact_line_remediation(&full_path_str, &content, p),
+                                    };
+                                    enrich_with_cves(&mut finding, &config_rule.cve_ids);
+                                    findings.push(finding);
                                     break;
                                 }
                             }
@@ -179,7 +390,7 @@ pub fn check_config_files(tool: &AiToolSecurityRule) -> Vec<SecurityFinding> {
                 for config_path in &tool.config_paths {
                     let full_path = home.join(config_path);
                     if full_path.exists() {
-                        findings.push(SecurityFinding {
+                        let mut finding = SecurityFinding {
                             tool_id: tool.id.clone(),
                             tool_name: tool.name.clone(),
                             issue: config_rule.name.clone(),
@@ -187,7 +398,10 @@ pub fn check_config_files(tool: &AiToolSecurityRule) -> Vec<SecurityFinding> {
                             risk_level: config_rule.risk_level,
                             remediation: config_rule.remediation.clone(),
                             details: format!("File exists: {}", full_path.display()),
-                        });
+                            remediation_action: None,
+                        };
+                        enrich_with_cves(&mut finding, &config_rule.cve_ids);
+                        findings.push(finding);
                     }
                 }
             }
@@ -200,7 +414,8 @@ pub fn check_config_files(tool: &AiToolSecurityRule) -> Vec<SecurityFinding> {
                             for entry in entries.flatten() {
                                 if let Ok(content) = fs::read_to_string(entry.path()) {
                                     if !content.contains(pattern) {
-                                        findings.push(SecurityFinding {
+                                        let entry_path = entry.path().display().to_string();
+                                        let mut finding = SecurityFinding {
                                             tool_id: tool.id.clone(),
                                             tool_name: tool.name.clone(),
                                             issue: config_rule.name.clone(),
@@ -209,10 +424,12 @@ pub fn check_config_files(tool: &AiToolSecurityRule) -> Vec<SecurityFinding> {
                                             remediation: config_rule.remediation.clone(),
                                             details: format!(
                                                 "Missing '{}' in: {}",
-                                                pattern,
-                                                entry.path().display()
+                                                pattern, entry_path
                                             ),
-                                        });
+                                            remediation_action: ensure_line_remediation(&entry_path, pattern),
+                                        };
+                                        enrich_with_cves(&mut finding, &config_rule.cve_ids);
+                                        findings.push(finding);
                                     }
                                 }
                             }
@@ -224,7 +441,7 @@ pub fn check_config_files(tool: &AiToolSecurityRule) -> Vec<SecurityFinding> {
                 if let Ok(value) = env::var(name) {
                     if let Some(insecure) = insecure_value {
                         if value == *insecure {
-                            findings.push(SecurityFinding {
+                            let mut finding = SecurityFinding {
                                 tool_id: tool.id.clone(),
                                 tool_name: tool.name.clone(),
                                 issue: config_rule.name.clone(),
@@ -232,17 +449,456 @@ pub fn check_config_files(tool: &AiToolSecurityRule) -> Vec<SecurityFinding> {
                                 risk_level: config_rule.risk_level,
                                 remediation: config_rule.remediation.clone(),
                                 details: format!("Env var {} has insecure value", name),
-                            });
+                                // No safe replacement value is defined on the rule itself,
+                                // so there's nothing un-ambiguous to rewrite `name` to.
+                                remediation_action: None,
+                            };
+                            enrich_with_cves(&mut finding, &config_rule.cve_ids);
+                            findings.push(finding);
                         }
                     }
                 }
             }
+            ConfigCheckType::JsonTaskAudit { path_pattern: _ } => {
+                for config_path in &tool.config_paths {
+                    let full_path = home.join(config_path);
+                    if !full_path.is_dir() {
+                        continue;
+                    }
+                    let Ok(entries) = fs::read_dir(&full_path) else {
+                        continue;
+                    };
+                    for entry in entries.flatten() {
+                        let entry_path = entry.path();
+                        if entry_path.file_name().and_then(|n| n.to_str()) != Some("tasks.json") {
+                            continue;
+                        }
+                        let Ok(content) = fs::read_to_string(&entry_path) else {
+                            continue;
+                        };
+                        let Some(reason) = audit_tasks_json(&content) else {
+                            continue;
+                        };
+                        let path_str = entry_path.display().to_string();
+                        let content_hash = ledger::hash_content(&content);
+                        if ledger::is_approved(&path_str, &content_hash) {
+                            continue;
+                        }
+                        let mut finding = SecurityFinding {
+                            tool_id: tool.id.clone(),
+                            tool_name: tool.name.clone(),
+                            issue: config_rule.name.clone(),
+                            description: config_rule.description.clone(),
+                            risk_level: config_rule.risk_level,
+                            remediation: config_rule.remediation.clone(),
+                            details: format!("{}: {}", path_str, reason),
+                            remediation_action: None,
+                        };
+                        enrich_with_cves(&mut finding, &config_rule.cve_ids);
+                        findings.push(finding);
+                    }
+                }
+            }
+            ConfigCheckType::SecretScan { path_pattern: _ } => {
+                // Recurse into subdirectories (see `collect_files_recursive`) so
+                // coverage matches `scan_path_for_secrets`, which this rule
+                // replaces the pattern-matching pass of for these tools (see
+                // `scan_config_secrets`) - a single-level walk here would quietly
+                // lose the depth the old pass had.
+                for config_path in &tool.config_paths {
+                    let full_path = home.join(config_path);
+                    if !full_path.exists() {
+                        continue;
+                    }
+                    let mut files = Vec::new();
+                    collect_files_recursive(&full_path, &mut files);
+                    for file in files {
+                        let Ok(content) = fs::read_to_string(&file) else {
+                            continue;
+                        };
+                        let file_path = file.display().to_string();
+                        if let Some(finding) = secret_scan_finding(
+                            tool,
+                            config_rule,
+                            &file_path,
+                            &content,
+                            &secret_patterns,
+                        ) {
+                            findings.push(finding);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    findings
+}
+
+/// Collect every regular file under `path`, recursing into subdirectories -
+/// `path` itself if it's already a file. Shared by the `SecretScan` config
+/// check so its coverage matches `scan_path_for_secrets`'s recursive walk.
+fn collect_files_recursive(path: &Path, out: &mut Vec<PathBuf>) {
+    if path.is_dir() {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                collect_files_recursive(&entry.path(), out);
+            }
+        }
+    } else if path.is_file() {
+        out.push(path.to_path_buf());
+    }
+}
+
+/// Build the `SecurityFinding` for a `SecretScan` rule match in `content`,
+/// `None` if nothing in `content` matched. Factored out so the directory and
+/// single-file cases in `check_config_files` share one copy of the
+/// risk-tiering and remediation-wiring logic instead of drifting apart.
+fn secret_scan_finding(
+    tool: &AiToolSecurityRule,
+    config_rule: &ConfigRule,
+    path: &str,
+    content: &str,
+    patterns: &[(&SecretPatternRule, Regex)],
+) -> Option<SecurityFinding> {
+    let (provider, matched, high_confidence) = scan_secret_in_content(content, patterns)?;
+    let mut finding = SecurityFinding {
+        tool_id: tool.id.clone(),
+        tool_name: tool.name.clone(),
+        issue: config_rule.name.clone(),
+        description: config_rule.description.clone(),
+        risk_level: if high_confidence {
+            config_rule.risk_level
+        } else {
+            one_tier_down(config_rule.risk_level)
+        },
+        remediation: config_rule.remediation.clone(),
+        details: format!("{} detected in: {} ({})", provider, path, redact_preview(&matched)),
+        // A guessed secret from the entropy fallback isn't confident enough
+        // to justify auto-rewriting the file - only a known-shape match
+        // gets a remediation.
+        remediation_action: high_confidence
+            .then(|| redact_line_remediation(path, content, &matched))
+            .flatten(),
+    };
+    enrich_with_cves(&mut finding, &config_rule.cve_ids);
+    Some(finding)
+}
+
+/// Scan `content` for a secret: first against `patterns` (the known
+/// per-provider shapes from `get_secret_patterns`, compiled once by the
+/// caller - see `check_config_files`), falling back to a Shannon-entropy
+/// gate over candidate tokens so a key in a rotated/unlisted format is
+/// still surfaced, just at lower confidence than a known-shape match.
+/// Returns `(provider label, raw matched text, is_high_confidence)` - the
+/// raw text (not yet redacted) so the caller can both build a
+/// `redact_preview` for display and hand the same text to
+/// `redact_line_remediation` to locate the line to rewrite.
+fn scan_secret_in_content(content: &str, patterns: &[(&SecretPatternRule, Regex)]) -> Option<(String, String, bool)> {
+    for (rule, re) in patterns {
+        if let Some(m) = re.find(content) {
+            return Some((rule.name.clone(), m.as_str().to_string(), true));
+        }
+    }
+
+    // Split on anything that isn't itself valid inside a base64/hex token,
+    // rather than on whitespace - a key embedded in compact JSON
+    // (`"apiKey":"..."`, no surrounding spaces) still isolates cleanly this
+    // way, where a whitespace split would leave the quotes/colon attached
+    // and fail the charset check below.
+    content
+        .split(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '+' | '/' | '=')))
+        .find_map(|token| {
+            is_high_entropy_secret_candidate(token)
+                .then(|| ("Unknown provider (high-entropy token)".to_string(), token.to_string(), false))
+        })
+}
+
+/// Minimum Shannon entropy, in bits per character, for a token to be treated
+/// as a possible secret rather than ordinary text - base64/hex-encoded
+/// random data typically sits well above this, while words, paths, and
+/// other low-variety text sit well below it. This fallback will also catch
+/// non-secret high-entropy values (hashes, UUIDs, cache keys) with no
+/// known-shape prefix to rule them out; unlike `JsonTaskAudit`, there's no
+/// approval ledger here to suppress a confirmed false positive, so it's
+/// reported at a lower confidence (`one_tier_down`) every scan rather than
+/// silently dropped.
+const ENTROPY_MIN_BITS_PER_CHAR: f64 = 4.0;
+/// Minimum token length to consider - most real API keys/tokens are at
+/// least this long, and shorter tokens need a much larger sample to make
+/// their entropy estimate meaningful at all.
+const ENTROPY_MIN_LEN: usize = 20;
+
+fn is_high_entropy_secret_candidate(token: &str) -> bool {
+    if token.len() < ENTROPY_MIN_LEN {
+        return false;
+    }
+    if !token.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '+' | '/' | '=')) {
+        return false;
+    }
+    shannon_entropy_bits_per_char(token) >= ENTROPY_MIN_BITS_PER_CHAR
+}
+
+/// Shannon entropy of `token`, in bits per character.
+fn shannon_entropy_bits_per_char(token: &str) -> f64 {
+    let len = token.chars().count() as f64;
+    if len == 0.0 {
+        return 0.0;
+    }
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for c in token.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts.values().fold(0.0, |entropy, &count| {
+        let p = count as f64 / len;
+        entropy - p * p.log2()
+    })
+}
+
+/// A marker substring (checked case-insensitively) that, found in a task's
+/// command or args, is treated as "this task can reach the network" -
+/// enough for a supply-chain payload to pull in a second stage.
+const NETWORK_COMMAND_MARKERS: &[&str] = &[
+    "curl", "wget", "invoke-webrequest", "iwr", "start-bitstransfer", "ncat", "nc -e",
+];
+
+/// Parse a `tasks.json`-shaped file and return a reason string for the first
+/// task that either auto-runs on folder open or invokes a shell command
+/// that looks capable of reaching the network, or `None` if every task
+/// looks benign (including when the content isn't valid JSON, or has no
+/// `tasks` array - there's nothing to flag either way).
+fn audit_tasks_json(content: &str) -> Option<String> {
+    // tasks.json is JSONC in both VS Code and Cursor (comments and trailing
+    // commas are both routinely present in real files) - `serde_json` alone
+    // would reject those and this check would silently never fire on them,
+    // so strip both before parsing.
+    let stripped = strip_trailing_commas(&strip_jsonc_comments(content));
+    let value: serde_json::Value = serde_json::from_str(&stripped).ok()?;
+    let tasks = value.get("tasks")?.as_array()?;
+
+    for task in tasks {
+        let label = task.get("label").and_then(|v| v.as_str()).unwrap_or("(unnamed task)");
+
+        let runs_on_folder_open = task
+            .get("runOptions")
+            .and_then(|r| r.get("runOn"))
+            .and_then(|v| v.as_str())
+            == Some("folderOpen");
+        if runs_on_folder_open {
+            return Some(format!("task '{}' runs automatically on folder open", label));
+        }
+
+        let command_text = task_command_text(task);
+        let command_lower = command_text.to_lowercase();
+        if NETWORK_COMMAND_MARKERS.iter().any(|marker| command_lower.contains(marker)) {
+            return Some(format!(
+                "task '{}' invokes a shell command that can reach the network: {}",
+                label, command_text
+            ));
+        }
+    }
+
+    None
+}
+
+/// Strip `//` line comments and `/* */` block comments out of JSONC content,
+/// tracking string literals (with escape handling) so a `//` or `/*` inside
+/// a quoted value - e.g. a URL in a task's `args` - isn't mistaken for one.
+fn strip_jsonc_comments(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(c),
         }
     }
 
+    out
+}
+
+/// Drop a trailing comma before a closing `}`/`]` - the other JSONC-ism
+/// `serde_json` won't parse. Crude (a regex over already comment-stripped
+/// text) rather than a full tokenizer, but sufficient for a best-effort
+/// audit check rather than a general-purpose JSONC parser.
+fn strip_trailing_commas(content: &str) -> String {
+    let re = Regex::new(r",(\s*[\]}])").expect("static trailing-comma regex is valid");
+    re.replace_all(content, "$1").to_string()
+}
+
+/// Join a task's `command` and `args` into one string for the network-marker
+/// scan above - a downloader invoked as `args: ["curl", "http://..."]`
+/// reads the same as one invoked as `command: "curl http://..."`.
+fn task_command_text(task: &serde_json::Value) -> String {
+    let command = task.get("command").and_then(|v| v.as_str()).unwrap_or("");
+    let args = task
+        .get("args")
+        .and_then(|v| v.as_array())
+        .map(|a| a.iter().filter_map(|v| v.as_str()).collect::<Vec<_>>().join(" "))
+        .unwrap_or_default();
+    format!("{} {}", command, args).trim().to_string()
+}
+
+/// Redact a matched secret down to a short, safe-to-display preview
+fn redact_preview(matched: &str) -> String {
+    let visible = matched.chars().take(6).collect::<String>();
+    format!("{}...redacted ({} chars)", visible, matched.len())
+}
+
+/// Check a file's permissions for being readable by other users on disk
+fn check_overly_permissive_mode(path: &PathBuf) -> Option<String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let metadata = fs::metadata(path).ok()?;
+        let mode = metadata.permissions().mode() & 0o777;
+        if mode & 0o077 != 0 {
+            return Some(format!("{:o}", mode));
+        }
+        None
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        None
+    }
+}
+
+/// Recursively scan a tool's config files for plaintext secrets and overly
+/// permissive file modes, driven by the `get_secret_patterns()` rule table
+/// so new provider key formats can be added without touching this scanner.
+///
+/// A tool whose rules already include a `ConfigCheckType::SecretScan`
+/// (see `check_config_files`) skips the pattern-matching pass here - that
+/// rule already covers the same `config_paths` against the same pattern
+/// table, plus the entropy fallback, and running both would double-report
+/// a single leaked credential. The file-permission check still runs for
+/// every tool, since nothing else performs it.
+pub fn scan_config_secrets(tool: &AiToolSecurityRule) -> Vec<SecurityFinding> {
+    let mut findings = Vec::new();
+    let home = match get_home_dir() {
+        Some(h) => h,
+        None => return findings,
+    };
+
+    let patterns: Vec<(&SecretPatternRule, Regex)> = get_secret_patterns()
+        .iter()
+        .filter_map(|rule| Regex::new(&rule.pattern).ok().map(|re| (rule, re)))
+        .collect();
+
+    let pattern_match_covered = tool
+        .configs
+        .iter()
+        .any(|c| matches!(c.check, ConfigCheckType::SecretScan { .. }));
+
+    for config_path in &tool.config_paths {
+        let full_path = home.join(config_path);
+        scan_path_for_secrets(tool, &full_path, &patterns, pattern_match_covered, &mut findings);
+    }
+
     findings
 }
 
+fn scan_path_for_secrets(
+    tool: &AiToolSecurityRule,
+    path: &PathBuf,
+    patterns: &[(&SecretPatternRule, Regex)],
+    pattern_match_covered: bool,
+    findings: &mut Vec<SecurityFinding>,
+) {
+    if path.is_dir() {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                scan_path_for_secrets(tool, &entry.path(), patterns, pattern_match_covered, findings);
+            }
+        }
+        return;
+    }
+
+    if !path.is_file() {
+        return;
+    }
+
+    if let Some(mode) = check_overly_permissive_mode(path) {
+        findings.push(SecurityFinding {
+            tool_id: tool.id.clone(),
+            tool_name: tool.name.clone(),
+            issue: "Overly permissive config file mode".into(),
+            description: "Config file is readable by group/other users, risking credential leakage on shared machines".into(),
+            risk_level: RiskLevel::Medium,
+            remediation: format!("chmod 600 {}", path.display()),
+            details: format!("{} has mode {}", path.display(), mode),
+            remediation_action: None,
+        });
+    }
+
+    if pattern_match_covered {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    for (rule, regex) in patterns {
+        if let Some(m) = regex.find(&content) {
+            findings.push(SecurityFinding {
+                tool_id: tool.id.clone(),
+                tool_name: tool.name.clone(),
+                issue: format!("{} found in config", rule.name),
+                description: "Plaintext credential detected in an AI tool config file".into(),
+                risk_level: RiskLevel::Critical,
+                remediation: "Move this credential to an environment variable or secret manager"
+                    .into(),
+                details: format!(
+                    "{}: {}",
+                    path.display(),
+                    redact_preview(m.as_str())
+                ),
+                remediation_action: None,
+            });
+        }
+    }
+}
+
 /// Check if a tool's process is running
 #[allow(dead_code)]
 fn is_tool_running(tool: &AiToolSecurityRule) -> bool {
@@ -260,20 +916,47 @@ fn is_tool_running(tool: &AiToolSecurityRule) -> bool {
     false
 }
 
+/// Cross-reference installed cargo and pip packages against their advisory
+/// databases (RustSec and OSV.dev respectively), producing findings in the
+/// same shape as the AI-tool checks above.
+///
+/// This hits the network on a cache miss (advisory-db clone/pull, OSV batch
+/// query), so unlike `get_security_findings` it is not run on the main scan
+/// path - callers should invoke it from a worker task the same way
+/// `PackageManager::check_outdated` is kept off the `scan_packages` path.
+pub fn scan_supply_chain() -> Vec<SecurityFinding> {
+    let mut findings = Vec::new();
+
+    for manager in discover_managers() {
+        match manager.name() {
+            "cargo" => findings.extend(scan_cargo_advisories(&manager.list_packages())),
+            "pip" => findings.extend(scan_pip_advisories(&manager.list_packages())),
+            _ => {}
+        }
+    }
+
+    findings
+}
+
 /// Get all security findings
 pub fn get_security_findings() -> Vec<SecurityFinding> {
     let ports_info = get_ports_in_use();
+    let host_ip = host_non_loopback_ip();
     let rules = get_rules();
     let mut all_findings = Vec::new();
 
     for tool in rules.iter() {
         // Check ports
-        let port_findings = check_exposed_ports(tool, &ports_info);
+        let port_findings = check_exposed_ports(tool, &ports_info, host_ip);
         all_findings.extend(port_findings);
 
         // Check configs
         let config_findings = check_config_files(tool);
         all_findings.extend(config_findings);
+
+        // Scan config file contents for plaintext secrets
+        let secret_findings = scan_config_secrets(tool);
+        all_findings.extend(secret_findings);
     }
 
     // Sort by risk level (Critical first)
@@ -336,10 +1019,12 @@ pub fn scan_specific_tool(tool_id: &str) -> Option<SecurityScanResult> {
         .find(|t| t.id == tool_id)?;
 
     let ports_info = get_ports_in_use();
+    let host_ip = host_non_loopback_ip();
     let mut findings = Vec::new();
 
-    findings.extend(check_exposed_ports(tool, &ports_info));
+    findings.extend(check_exposed_ports(tool, &ports_info, host_ip));
     findings.extend(check_config_files(tool));
+    findings.extend(scan_config_secrets(tool));
 
     let summary = SecuritySummary {
         total_findings: findings.len(),